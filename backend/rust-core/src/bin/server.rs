@@ -5,7 +5,11 @@
  * that can be called from Python via subprocess.
  */
 
-use serde_json::{Value, json};
+use rust_core::geometry::{
+    circle_circle_intersection, line_circle_intersection, line_line_intersection, Boundedness,
+    Circle, Line, Point,
+};
+use serde_json::{Map, Value, json};
 use std::io::{self, Read};
 
 fn main() {
@@ -139,15 +143,16 @@ fn process_command(command: Value) -> Value {
         Some("find_intersections") => {
             let obj1_id = command.get("obj1_id").and_then(|v| v.as_str()).unwrap_or("");
             let obj2_id = command.get("obj2_id").and_then(|v| v.as_str()).unwrap_or("");
-            
-            // For now, return empty intersections
-            // TODO: Implement actual intersection calculations
-            json!({
-                "intersections": [],
-                "construction_space": command.get("construction_space").unwrap_or(&json!({
-                    "points": {}, "lines": {}, "circles": {}, "history": []
-                }))
-            })
+            let empty_space = json!({"points": {}, "lines": {}, "circles": {}, "history": []});
+            let space = command.get("construction_space").unwrap_or(&empty_space);
+
+            match find_intersections(space, obj1_id, obj2_id) {
+                Ok((intersections, construction_space)) => json!({
+                    "intersections": intersections,
+                    "construction_space": construction_space
+                }),
+                Err(message) => json!({ "error": message }),
+            }
         }
         
         Some("validate_construction") => {
@@ -164,4 +169,239 @@ fn process_command(command: Value) -> Value {
             })
         }
     }
+}
+
+/// What kind of geometric object an ID refers to within a JSON construction space
+enum ObjectKind {
+    Point,
+    Line,
+    Circle,
+}
+
+fn object_kind(space: &Value, id: &str) -> Option<ObjectKind> {
+    if space.get("lines").and_then(|v| v.get(id)).is_some() {
+        Some(ObjectKind::Line)
+    } else if space.get("circles").and_then(|v| v.get(id)).is_some() {
+        Some(ObjectKind::Circle)
+    } else if space.get("points").and_then(|v| v.get(id)).is_some() {
+        Some(ObjectKind::Point)
+    } else {
+        None
+    }
+}
+
+fn point_from_json(points: &Map<String, Value>, id: &str) -> Result<Point, String> {
+    let entry = points
+        .get(id)
+        .ok_or_else(|| format!("point not found: {}", id))?;
+    let x = entry
+        .get("x")
+        .and_then(|v| v.as_f64())
+        .ok_or_else(|| format!("point {} missing x", id))?;
+    let y = entry
+        .get("y")
+        .and_then(|v| v.as_f64())
+        .ok_or_else(|| format!("point {} missing y", id))?;
+    let label = entry
+        .get("label")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+
+    Ok(Point {
+        id: id.to_string(),
+        position: nalgebra::Point2::new(x, y),
+        label,
+        is_constructed: false,
+        dependencies: Vec::new(),
+    })
+}
+
+/// Reconstruct a line and its two defining points from the JSON construction space
+fn line_from_space(space: &Value, id: &str) -> Result<(Line, Point, Point), String> {
+    let entry = space
+        .get("lines")
+        .and_then(|v| v.get(id))
+        .ok_or_else(|| format!("line not found: {}", id))?;
+    let point1_id = entry
+        .get("point1_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("line {} missing point1_id", id))?
+        .to_string();
+    let point2_id = entry
+        .get("point2_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("line {} missing point2_id", id))?
+        .to_string();
+    let label = entry
+        .get("label")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+
+    let points = space
+        .get("points")
+        .and_then(|v| v.as_object())
+        .ok_or_else(|| "construction_space.points is not an object".to_string())?;
+    let p1 = point_from_json(points, &point1_id)?;
+    let p2 = point_from_json(points, &point2_id)?;
+
+    let line = Line {
+        id: id.to_string(),
+        point1_id,
+        point2_id,
+        label,
+        dependencies: vec![p1.id.clone(), p2.id.clone()],
+        bounds: Boundedness::Line,
+    };
+
+    Ok((line, p1, p2))
+}
+
+/// Reconstruct a circle and its center/radius points from the JSON construction space
+fn circle_from_space(space: &Value, id: &str) -> Result<(Circle, Point, Point), String> {
+    let entry = space
+        .get("circles")
+        .and_then(|v| v.get(id))
+        .ok_or_else(|| format!("circle not found: {}", id))?;
+    let center_id = entry
+        .get("center_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("circle {} missing center_id", id))?
+        .to_string();
+    let radius_point_id = entry
+        .get("radius_point_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("circle {} missing radius_point_id", id))?
+        .to_string();
+    let label = entry
+        .get("label")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+
+    let points = space
+        .get("points")
+        .and_then(|v| v.as_object())
+        .ok_or_else(|| "construction_space.points is not an object".to_string())?;
+    let center = point_from_json(points, &center_id)?;
+    let radius_point = point_from_json(points, &radius_point_id)?;
+
+    let circle = Circle {
+        id: id.to_string(),
+        center_id,
+        radius_point_id,
+        label,
+        dependencies: vec![center.id.clone(), radius_point.id.clone()],
+    };
+
+    Ok((circle, center, radius_point))
+}
+
+fn point_to_json(point: &Point) -> Value {
+    json!({
+        "id": point.id,
+        "x": point.position.x,
+        "y": point.position.y,
+        "label": point.label,
+        "is_constructed": point.is_constructed,
+        "dependencies": point.dependencies,
+    })
+}
+
+/// Look up `obj1_id`/`obj2_id` in the JSON construction space, dispatch to the
+/// matching intersection routine, and fold the resulting points back into
+/// `construction_space.points`. Returns the new points and the updated space.
+fn find_intersections(space: &Value, obj1_id: &str, obj2_id: &str) -> Result<(Vec<Value>, Value), String> {
+    let kind1 = object_kind(space, obj1_id).ok_or_else(|| format!("object not found: {}", obj1_id))?;
+    let kind2 = object_kind(space, obj2_id).ok_or_else(|| format!("object not found: {}", obj2_id))?;
+
+    let points = match (kind1, kind2) {
+        (ObjectKind::Line, ObjectKind::Line) => {
+            let (line1, p1a, p1b) = line_from_space(space, obj1_id)?;
+            let (line2, p2a, p2b) = line_from_space(space, obj2_id)?;
+            line_line_intersection(&line1, &p1a, &p1b, &line2, &p2a, &p2b)
+        }
+        (ObjectKind::Line, ObjectKind::Circle) => {
+            let (line, p1, p2) = line_from_space(space, obj1_id)?;
+            let (circle, center, radius_point) = circle_from_space(space, obj2_id)?;
+            line_circle_intersection(&line, &p1, &p2, &circle, &center, &radius_point)
+        }
+        (ObjectKind::Circle, ObjectKind::Line) => {
+            let (circle, center, radius_point) = circle_from_space(space, obj1_id)?;
+            let (line, p1, p2) = line_from_space(space, obj2_id)?;
+            line_circle_intersection(&line, &p1, &p2, &circle, &center, &radius_point)
+        }
+        (ObjectKind::Circle, ObjectKind::Circle) => {
+            let (circle1, center1, radius_point1) = circle_from_space(space, obj1_id)?;
+            let (circle2, center2, radius_point2) = circle_from_space(space, obj2_id)?;
+            circle_circle_intersection(
+                &circle1,
+                &center1,
+                &radius_point1,
+                &circle2,
+                &center2,
+                &radius_point2,
+            )
+        }
+        _ => return Err("intersections are only defined between lines and circles".to_string()),
+    }
+    .map_err(|e| e.to_string())?;
+
+    let mut updated_space = space.clone();
+    let points_map = updated_space
+        .get_mut("points")
+        .and_then(|v| v.as_object_mut())
+        .ok_or_else(|| "construction_space.points is not an object".to_string())?;
+
+    let mut results = Vec::with_capacity(points.len());
+    for point in &points {
+        let entry = point_to_json(point);
+        points_map.insert(point.id.clone(), entry.clone());
+        results.push(entry);
+    }
+
+    Ok((results, updated_space))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_space() -> Value {
+        json!({
+            "points": {
+                "A": {"id": "A", "x": 0.0, "y": 0.0, "label": "A"},
+                "B": {"id": "B", "x": 2.0, "y": 0.0, "label": "B"},
+                "C": {"id": "C", "x": 1.0, "y": -1.0, "label": "C"},
+                "D": {"id": "D", "x": 1.0, "y": 1.0, "label": "D"}
+            },
+            "lines": {
+                "L1": {"id": "L1", "point1_id": "A", "point2_id": "B", "label": "L1"},
+                "L2": {"id": "L2", "point1_id": "C", "point2_id": "D", "label": "L2"}
+            },
+            "circles": {},
+            "history": []
+        })
+    }
+
+    #[test]
+    fn test_find_intersections_line_line() {
+        let space = sample_space();
+        let (points, updated_space) = find_intersections(&space, "L1", "L2").unwrap();
+
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0]["x"].as_f64().unwrap(), 1.0);
+        assert_eq!(points[0]["y"].as_f64().unwrap(), 0.0);
+
+        let new_point_id = points[0]["id"].as_str().unwrap();
+        assert!(updated_space["points"].get(new_point_id).is_some());
+    }
+
+    #[test]
+    fn test_find_intersections_missing_object() {
+        let space = sample_space();
+        let err = find_intersections(&space, "missing", "L2").unwrap_err();
+        assert!(err.contains("missing"));
+    }
 }
\ No newline at end of file
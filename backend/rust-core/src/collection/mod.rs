@@ -213,6 +213,45 @@ impl ElementCollection {
     pub fn get_strongest_element(&self) -> Option<&CollectedElement> {
         self.elements.values().max_by_key(|e| e.stats.power)
     }
+
+    /// Roll a rarity-weighted encounter from the standing spawn catalog,
+    /// deterministically reproducible from `rng_seed`
+    pub fn roll_encounter(&self, rng_seed: u64) -> CollectedElement {
+        let table = crate::encounter::WeightedSpawnTable::new(crate::encounter::default_candidates());
+        let mut rng = crate::encounter::SplitMix64::new(rng_seed);
+        // default_candidates() is never empty, so the table always has an entry to draw
+        table.sample(&mut rng).expect("default spawn catalog is never empty").clone()
+    }
+
+    /// The `k` caught elements with the highest `stats.power`, strongest first
+    pub fn top_k_strongest(&self, k: usize) -> Vec<&CollectedElement> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let elements: Vec<&CollectedElement> = self.elements.values().collect();
+        let mut heap: std::collections::BinaryHeap<std::cmp::Reverse<(u8, usize)>> =
+            std::collections::BinaryHeap::with_capacity(k + 1);
+
+        for (idx, element) in elements.iter().enumerate() {
+            let power = element.stats.power;
+            if heap.len() < k {
+                heap.push(std::cmp::Reverse((power, idx)));
+            } else if let Some(&std::cmp::Reverse((min_power, _))) = heap.peek() {
+                if power > min_power {
+                    heap.pop();
+                    heap.push(std::cmp::Reverse((power, idx)));
+                }
+            }
+        }
+
+        let mut result: Vec<&CollectedElement> = heap
+            .into_iter()
+            .map(|std::cmp::Reverse((_, idx))| elements[idx])
+            .collect();
+        result.sort_by(|a, b| b.stats.power.cmp(&a.stats.power));
+        result
+    }
 }
 
 /// Available construction tools based on collection
@@ -311,6 +350,67 @@ impl ElementFactory {
         }
     }
 
+    /// Create a triangle element classified by its angle/side relationship
+    pub fn create_triangle(triangle_type: TriangleType) -> CollectedElement {
+        let (name, rarity) = match &triangle_type {
+            TriangleType::Equilateral => ("Equilateral Triangle", Rarity::Rare),
+            TriangleType::Isosceles => ("Isosceles Triangle", Rarity::Uncommon),
+            TriangleType::Right => ("Right Triangle", Rarity::Uncommon),
+            TriangleType::Obtuse => ("Obtuse Triangle", Rarity::Common),
+            TriangleType::Acute => ("Acute Triangle", Rarity::Common),
+            TriangleType::Scalene => ("Scalene Triangle", Rarity::Common),
+        };
+
+        CollectedElement {
+            id: Uuid::new_v4().to_string(),
+            element_type: ElementType::Triangle { triangle_type },
+            name: name.to_string(),
+            description: "A three-sided figure discovered among the construction's points"
+                .to_string(),
+            rarity,
+            stats: ElementStats {
+                precision: 80,
+                complexity: 40,
+                elegance: 60,
+                power: 50,
+                rarity_score: 3,
+            },
+            unlock_requirements: vec!["Point".to_string()],
+            unlocks: vec![],
+            caught_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Create a polygon element with rarity scaled by its vertex count
+    pub fn create_polygon(sides: usize) -> CollectedElement {
+        let rarity = match sides {
+            0..=3 => Rarity::Common,
+            4 => Rarity::Uncommon,
+            5..=6 => Rarity::Rare,
+            7..=10 => Rarity::Epic,
+            _ => Rarity::Legendary,
+        };
+
+        CollectedElement {
+            id: Uuid::new_v4().to_string(),
+            element_type: ElementType::Polygon { sides },
+            name: format!("{}-gon", sides),
+            description: "A closed figure traced over the construction's caught points"
+                .to_string(),
+            rarity,
+            stats: ElementStats {
+                precision: 75,
+                complexity: (sides * 10).min(100) as u8,
+                elegance: 65,
+                power: (sides * 8).min(100) as u8,
+                rarity_score: (sides as u8).min(100),
+            },
+            unlock_requirements: vec!["Point".to_string()],
+            unlocks: vec![],
+            caught_at: chrono::Utc::now(),
+        }
+    }
+
     /// Create Euclid's first proposition (equilateral triangle)
     pub fn create_equilateral_triangle() -> CollectedElement {
         CollectedElement {
@@ -413,4 +513,31 @@ mod tests {
         assert!(stats.total_power > 0);
         assert!(stats.favorite_element.is_some());
     }
+
+    #[test]
+    fn test_roll_encounter_is_deterministic() {
+        let collection = ElementCollection::new();
+        let a = collection.roll_encounter(99);
+        let b = collection.roll_encounter(99);
+        assert_eq!(a.name, b.name);
+    }
+
+    #[test]
+    fn test_top_k_strongest_orders_by_power_descending() {
+        let mut collection = ElementCollection::new();
+        collection.catch_element(ElementFactory::create_point()); // power 20
+        collection.catch_element(ElementFactory::create_line()); // power 40
+        collection.catch_element(ElementFactory::create_circle()); // power 60
+
+        let top2 = collection.top_k_strongest(2);
+        assert_eq!(top2.len(), 2);
+        assert_eq!(top2[0].name, "Circle");
+        assert_eq!(top2[1].name, "Line");
+    }
+
+    #[test]
+    fn test_top_k_strongest_zero_is_empty() {
+        let collection = ElementCollection::new();
+        assert!(collection.top_k_strongest(0).is_empty());
+    }
 }
\ No newline at end of file
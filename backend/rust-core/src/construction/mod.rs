@@ -1,185 +1,399 @@
 //! Construction management and validation system
 
-use crate::geometry::{GeometricObject, Point, Line, Circle};
+use crate::geometry::{GeometricObject, Point, Line, Circle, Boundedness, Intersection, EPSILON};
+use crate::geometry::operations::segment_segment_intersection;
+use crate::utils::slab::IndexSlab;
 use crate::{GeometryError, Result};
+use nalgebra::{Point2, Vector2};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// A construction space containing all geometric objects and their relationships
+///
+/// Points, lines, and circles live in `IndexSlab`s keyed by compact integer
+/// handles; the `*_ids` maps are a thin string-to-handle lookup table kept
+/// only for the WASM boundary, where callers address objects by UUID.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConstructionSpace {
-    /// All points in the construction
-    pub points: HashMap<String, Point>,
-    /// All lines in the construction
-    pub lines: HashMap<String, Line>,
-    /// All circles in the construction
-    pub circles: HashMap<String, Circle>,
+    points: IndexSlab<Point>,
+    lines: IndexSlab<Line>,
+    circles: IndexSlab<Circle>,
+    point_ids: HashMap<String, usize>,
+    line_ids: HashMap<String, usize>,
+    circle_ids: HashMap<String, usize>,
     /// Construction history for playback
     pub history: Vec<ConstructionStep>,
+    /// How each constructed point was derived, so `ConstructionGraph` can
+    /// recompute it when one of its inputs moves
+    derivations: HashMap<String, Derivation>,
 }
 
 impl ConstructionSpace {
     /// Create a new empty construction space
     pub fn new() -> Self {
         Self {
-            points: HashMap::new(),
-            lines: HashMap::new(),
-            circles: HashMap::new(),
+            points: IndexSlab::new(),
+            lines: IndexSlab::new(),
+            circles: IndexSlab::new(),
+            point_ids: HashMap::new(),
+            line_ids: HashMap::new(),
+            circle_ids: HashMap::new(),
             history: Vec::new(),
+            derivations: HashMap::new(),
         }
     }
 
     /// Add a point to the construction space
     pub fn add_point(&mut self, point: Point) -> String {
+        self.add_point_with_derivation(point, None)
+    }
+
+    /// Add a point that was derived from other objects (by `find_intersections`
+    /// or a parallel/perpendicular construction), recording `derivation` both in `self.derivations`
+    /// (for live recomputation) and in the `AddPoint` step itself (so `replay`
+    /// can restore `self.derivations` on the rebuilt space)
+    fn add_point_with_derivation(&mut self, point: Point, derivation: Option<Derivation>) -> String {
         let id = point.id.clone();
-        let step = ConstructionStep::AddPoint { point: point.clone() };
+        let step = ConstructionStep::AddPoint { point: point.clone(), derivation: derivation.clone() };
         self.history.push(step);
-        self.points.insert(id.clone(), point);
+        let handle = self.points.insert(point);
+        self.point_ids.insert(id.clone(), handle);
+        if let Some(derivation) = derivation {
+            self.derivations.insert(id.clone(), derivation);
+        }
         id
     }
 
-    /// Construct a line through two points
+    /// Construct an infinite line through two points
     pub fn construct_line(&mut self, point1_id: &str, point2_id: &str, label: Option<String>) -> Result<String> {
+        self.construct_bounded_line(point1_id, point2_id, Boundedness::Line, label)
+    }
+
+    /// Construct a segment between two points: a line that stops at both
+    pub fn construct_segment(&mut self, point1_id: &str, point2_id: &str, label: Option<String>) -> Result<String> {
+        self.construct_bounded_line(point1_id, point2_id, Boundedness::Segment, label)
+    }
+
+    /// Construct a ray from `point1_id` through `point2_id`, extending
+    /// infinitely past `point2_id` but stopping at `point1_id`
+    pub fn construct_ray(&mut self, point1_id: &str, point2_id: &str, label: Option<String>) -> Result<String> {
+        self.construct_bounded_line(point1_id, point2_id, Boundedness::Ray, label)
+    }
+
+    /// Construct a line through two points with the given `bounds`
+    fn construct_bounded_line(&mut self, point1_id: &str, point2_id: &str, bounds: Boundedness, label: Option<String>) -> Result<String> {
         // Validate that points exist
-        if !self.points.contains_key(point1_id) {
-            return Err(GeometryError::PointNotFound { 
-                id: point1_id.to_string() 
+        if !self.point_ids.contains_key(point1_id) {
+            return Err(GeometryError::PointNotFound {
+                id: point1_id.to_string()
             });
         }
-        if !self.points.contains_key(point2_id) {
-            return Err(GeometryError::PointNotFound { 
-                id: point2_id.to_string() 
+        if !self.point_ids.contains_key(point2_id) {
+            return Err(GeometryError::PointNotFound {
+                id: point2_id.to_string()
             });
         }
 
         // Check that points are not the same
         if point1_id == point2_id {
-            return Err(GeometryError::InvalidConstruction { 
-                reason: "Cannot create line with identical points".to_string() 
+            return Err(GeometryError::InvalidConstruction {
+                reason: "Cannot create line with identical points".to_string()
             });
         }
 
-        let line = Line::new(point1_id.to_string(), point2_id.to_string(), label);
+        let line = Line::new_bounded(point1_id.to_string(), point2_id.to_string(), bounds, label);
         let id = line.id.clone();
-        
-        let step = ConstructionStep::ConstructLine { 
+
+        let step = ConstructionStep::ConstructLine {
             line: line.clone(),
             point1_id: point1_id.to_string(),
             point2_id: point2_id.to_string(),
         };
         self.history.push(step);
-        self.lines.insert(id.clone(), line);
-        
+        let handle = self.lines.insert(line);
+        self.line_ids.insert(id.clone(), handle);
+
         Ok(id)
     }
 
     /// Construct a circle with center and radius point
     pub fn construct_circle(&mut self, center_id: &str, radius_point_id: &str, label: Option<String>) -> Result<String> {
         // Validate that points exist
-        if !self.points.contains_key(center_id) {
-            return Err(GeometryError::PointNotFound { 
-                id: center_id.to_string() 
+        if !self.point_ids.contains_key(center_id) {
+            return Err(GeometryError::PointNotFound {
+                id: center_id.to_string()
             });
         }
-        if !self.points.contains_key(radius_point_id) {
-            return Err(GeometryError::PointNotFound { 
-                id: radius_point_id.to_string() 
+        if !self.point_ids.contains_key(radius_point_id) {
+            return Err(GeometryError::PointNotFound {
+                id: radius_point_id.to_string()
             });
         }
 
         // Check that points are not the same
         if center_id == radius_point_id {
-            return Err(GeometryError::InvalidConstruction { 
-                reason: "Center and radius point cannot be the same".to_string() 
+            return Err(GeometryError::InvalidConstruction {
+                reason: "Center and radius point cannot be the same".to_string()
             });
         }
 
         let circle = Circle::new(center_id.to_string(), radius_point_id.to_string(), label);
         let id = circle.id.clone();
-        
-        let step = ConstructionStep::ConstructCircle { 
+
+        let step = ConstructionStep::ConstructCircle {
             circle: circle.clone(),
             center_id: center_id.to_string(),
             radius_point_id: radius_point_id.to_string(),
         };
         self.history.push(step);
-        self.circles.insert(id.clone(), circle);
-        
+        let handle = self.circles.insert(circle);
+        self.circle_ids.insert(id.clone(), handle);
+
         Ok(id)
     }
 
-    /// Find intersections between two geometric objects
-    pub fn find_intersections(&mut self, obj1_id: &str, obj2_id: &str) -> Result<Vec<Point>> {
-        use crate::geometry::operations::{line_line_intersection, line_circle_intersection, circle_circle_intersection};
-
-        // Determine object types and get intersections
-        let intersections = if let (Some(line1), Some(line2)) = (self.lines.get(obj1_id), self.lines.get(obj2_id)) {
-            // Line-Line intersection
-            let p1a = self.points.get(&line1.point1_id).unwrap();
-            let p1b = self.points.get(&line1.point2_id).unwrap();
-            let p2a = self.points.get(&line2.point1_id).unwrap();
-            let p2b = self.points.get(&line2.point2_id).unwrap();
-            line_line_intersection(line1, p1a, p1b, line2, p2a, p2b)?
-        } else if let (Some(line), Some(circle)) = (self.lines.get(obj1_id), self.circles.get(obj2_id)) {
+    /// Look up a point by its string ID
+    pub fn get_point(&self, id: &str) -> Option<&Point> {
+        self.point_ids.get(id).and_then(|&handle| self.points.get(handle))
+    }
+
+    /// Look up a line by its string ID
+    pub fn get_line(&self, id: &str) -> Option<&Line> {
+        self.line_ids.get(id).and_then(|&handle| self.lines.get(handle))
+    }
+
+    /// Look up a circle by its string ID
+    pub fn get_circle(&self, id: &str) -> Option<&Circle> {
+        self.circle_ids.get(id).and_then(|&handle| self.circles.get(handle))
+    }
+
+    /// Iterate over all points
+    pub fn points(&self) -> impl Iterator<Item = &Point> {
+        self.points.iter()
+    }
+
+    /// Iterate over all lines
+    pub fn lines(&self) -> impl Iterator<Item = &Line> {
+        self.lines.iter()
+    }
+
+    /// Iterate over all circles
+    pub fn circles(&self) -> impl Iterator<Item = &Circle> {
+        self.circles.iter()
+    }
+
+    /// Number of points currently stored
+    pub fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of lines currently stored
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Number of circles currently stored
+    pub fn circle_count(&self) -> usize {
+        self.circles.len()
+    }
+
+    /// Compute the intersection points of two geometric objects without
+    /// adding them to the construction space. Shared by `find_intersections`
+    /// and `ConstructionGraph`'s recomputation pass, which needs the same
+    /// dispatch to replay a `Derivation::Intersection` without minting a new
+    /// point each time.
+    pub fn compute_intersections(&self, obj1_id: &str, obj2_id: &str) -> Result<Vec<Point>> {
+        use crate::geometry::operations::{line_line_intersection_bounded, line_circle_intersection_bounded, circle_circle_intersection};
+
+        if let (Some(line1), Some(line2)) = (self.get_line(obj1_id), self.get_line(obj2_id)) {
+            // Line-Line intersection, honoring each line's own bounds (an
+            // unbounded `Line` behaves exactly as the old infinite-line code did)
+            let p1a = self.get_point(&line1.point1_id).unwrap();
+            let p1b = self.get_point(&line1.point2_id).unwrap();
+            let p2a = self.get_point(&line2.point1_id).unwrap();
+            let p2b = self.get_point(&line2.point2_id).unwrap();
+            line_line_intersection_bounded(line1, p1a, p1b, line1.bounds, line2, p2a, p2b, line2.bounds)
+        } else if let (Some(line), Some(circle)) = (self.get_line(obj1_id), self.get_circle(obj2_id)) {
             // Line-Circle intersection
-            let p1 = self.points.get(&line.point1_id).unwrap();
-            let p2 = self.points.get(&line.point2_id).unwrap();
-            let center = self.points.get(&circle.center_id).unwrap();
-            let radius_point = self.points.get(&circle.radius_point_id).unwrap();
-            line_circle_intersection(line, p1, p2, circle, center, radius_point)?
-        } else if let (Some(circle), Some(line)) = (self.circles.get(obj1_id), self.lines.get(obj2_id)) {
+            let p1 = self.get_point(&line.point1_id).unwrap();
+            let p2 = self.get_point(&line.point2_id).unwrap();
+            let center = self.get_point(&circle.center_id).unwrap();
+            let radius_point = self.get_point(&circle.radius_point_id).unwrap();
+            line_circle_intersection_bounded(line, p1, p2, line.bounds, circle, center, radius_point)
+        } else if let (Some(circle), Some(line)) = (self.get_circle(obj1_id), self.get_line(obj2_id)) {
             // Circle-Line intersection (swap order)
-            let p1 = self.points.get(&line.point1_id).unwrap();
-            let p2 = self.points.get(&line.point2_id).unwrap();
-            let center = self.points.get(&circle.center_id).unwrap();
-            let radius_point = self.points.get(&circle.radius_point_id).unwrap();
-            line_circle_intersection(line, p1, p2, circle, center, radius_point)?
-        } else if let (Some(circle1), Some(circle2)) = (self.circles.get(obj1_id), self.circles.get(obj2_id)) {
-            // Circle-Circle intersection
-            let center1 = self.points.get(&circle1.center_id).unwrap();
-            let radius_point1 = self.points.get(&circle1.radius_point_id).unwrap();
-            let center2 = self.points.get(&circle2.center_id).unwrap();
-            let radius_point2 = self.points.get(&circle2.radius_point_id).unwrap();
-            circle_circle_intersection(circle1, center1, radius_point1, circle2, center2, radius_point2)?
+            let p1 = self.get_point(&line.point1_id).unwrap();
+            let p2 = self.get_point(&line.point2_id).unwrap();
+            let center = self.get_point(&circle.center_id).unwrap();
+            let radius_point = self.get_point(&circle.radius_point_id).unwrap();
+            line_circle_intersection_bounded(line, p1, p2, line.bounds, circle, center, radius_point)
+        } else if let (Some(circle1), Some(circle2)) = (self.get_circle(obj1_id), self.get_circle(obj2_id)) {
+            // Circle-Circle intersection: defer to classify_circles so a
+            // relationship that isn't actually crossing (equal, tangent
+            // aside, contained/contains, disjoint) can't produce degenerate
+            // duplicate points from floating-point noise near the boundary
+            match self.classify_circles(obj1_id, obj2_id)? {
+                CircleRelationship::Intersecting(_) | CircleRelationship::Tangent => {
+                    let center1 = self.get_point(&circle1.center_id).unwrap();
+                    let radius_point1 = self.get_point(&circle1.radius_point_id).unwrap();
+                    let center2 = self.get_point(&circle2.center_id).unwrap();
+                    let radius_point2 = self.get_point(&circle2.radius_point_id).unwrap();
+                    circle_circle_intersection(circle1, center1, radius_point1, circle2, center2, radius_point2)
+                }
+                CircleRelationship::Equal
+                | CircleRelationship::Contained
+                | CircleRelationship::Contains
+                | CircleRelationship::Disjoint => Ok(Vec::new()),
+            }
         } else {
-            return Err(GeometryError::InvalidConstruction { 
-                reason: "Invalid object IDs for intersection".to_string() 
-            });
-        };
+            Err(GeometryError::InvalidConstruction {
+                reason: "Invalid object IDs for intersection".to_string()
+            })
+        }
+    }
+
+    /// Find intersections between two geometric objects, adding each result
+    /// to the construction space with a recorded `Derivation` so it can be
+    /// recomputed later if `obj1_id` or `obj2_id` moves.
+    pub fn find_intersections(&mut self, obj1_id: &str, obj2_id: &str) -> Result<Vec<Point>> {
+        let intersections = self.compute_intersections(obj1_id, obj2_id)?;
 
-        // Add intersection points to the construction space
         let mut point_ids = Vec::new();
-        for point in intersections {
-            let id = self.add_point(point);
+        for (index, point) in intersections.into_iter().enumerate() {
+            let id = self.add_point_with_derivation(
+                point,
+                Some(Derivation::Intersection {
+                    obj1_id: obj1_id.to_string(),
+                    obj2_id: obj2_id.to_string(),
+                    index,
+                }),
+            );
             point_ids.push(id);
         }
 
-        // Get the points to return
         let result_points: Vec<Point> = point_ids.iter()
-            .map(|id| self.points.get(id).unwrap().clone())
+            .map(|id| self.get_point(id).unwrap().clone())
             .collect();
 
         Ok(result_points)
     }
 
+    /// The recorded `Derivation` for a constructed point, if any
+    pub fn derivation(&self, point_id: &str) -> Option<&Derivation> {
+        self.derivations.get(point_id)
+    }
+
+    /// Intersect the segments between two lines' defining points, reporting a
+    /// single crossing point, the overlapping sub-segment of two collinear
+    /// segments, or no intersection at all. Each `Line`'s own `bounds` is not
+    /// consulted: a ray or full line is clipped to its two defining points here too. Unlike `compute_intersections`, this never mints
+    /// a new constructed `Point` — it's a read-only query for callers that
+    /// need to tell an `Overlap` apart from a `Point` rather than just a
+    /// `Vec<Point>`.
+    pub fn classify_segments(&self, line1_id: &str, line2_id: &str) -> Result<Intersection> {
+        let line1 = self.get_line(line1_id).ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("line {} not found", line1_id),
+        })?;
+        let line2 = self.get_line(line2_id).ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("line {} not found", line2_id),
+        })?;
+
+        let p1a = self.get_point(&line1.point1_id).unwrap();
+        let p1b = self.get_point(&line1.point2_id).unwrap();
+        let p2a = self.get_point(&line2.point1_id).unwrap();
+        let p2b = self.get_point(&line2.point2_id).unwrap();
+
+        Ok(segment_segment_intersection(p1a.position, p1b.position, p2a.position, p2b.position))
+    }
+
+    /// Classify how two circles relate, from their center distance `d` and
+    /// radii `r1, r2`, without computing (or caring about) their actual
+    /// intersection points. Lets a caller detect e.g. tangency directly,
+    /// and lets `compute_intersections` avoid returning degenerate
+    /// duplicate points for configurations that aren't really crossing.
+    pub fn classify_circles(&self, circle1_id: &str, circle2_id: &str) -> Result<CircleRelationship> {
+        let circle1 = self.get_circle(circle1_id).ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("circle {} not found", circle1_id),
+        })?;
+        let circle2 = self.get_circle(circle2_id).ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("circle {} not found", circle2_id),
+        })?;
+
+        let center1 = self.get_point(&circle1.center_id).unwrap();
+        let radius_point1 = self.get_point(&circle1.radius_point_id).unwrap();
+        let center2 = self.get_point(&circle2.center_id).unwrap();
+        let radius_point2 = self.get_point(&circle2.radius_point_id).unwrap();
+
+        let r1 = circle1.radius(center1, radius_point1);
+        let r2 = circle2.radius(center2, radius_point2);
+        let d = center1.distance_to(center2);
+
+        if d < EPSILON && (r1 - r2).abs() < EPSILON {
+            return Ok(CircleRelationship::Equal);
+        }
+        if (d - (r1 + r2)).abs() < EPSILON || (d - (r1 - r2).abs()).abs() < EPSILON {
+            return Ok(CircleRelationship::Tangent);
+        }
+        if (r1 - r2).abs() < d && d < r1 + r2 {
+            return Ok(CircleRelationship::Intersecting(2));
+        }
+        if d + r1.min(r2) < r1.max(r2) {
+            return Ok(if r1 > r2 { CircleRelationship::Contains } else { CircleRelationship::Contained });
+        }
+        Ok(CircleRelationship::Disjoint)
+    }
+
+    /// Overwrite a point's position in place, without touching its
+    /// dependencies or `is_constructed` flag. Used by `ConstructionGraph` to
+    /// refresh a derived point, and by `move_point` to relocate a base point.
+    pub fn set_point_position(&mut self, point_id: &str, x: f64, y: f64) -> Result<()> {
+        let &handle = self.point_ids.get(point_id).ok_or_else(|| GeometryError::PointNotFound {
+            id: point_id.to_string(),
+        })?;
+        let point = self.points.get_mut(handle).ok_or_else(|| GeometryError::PointNotFound {
+            id: point_id.to_string(),
+        })?;
+        point.position = Point2::new(x, y);
+        Ok(())
+    }
+
+    /// Move a base (non-constructed) point to `(x, y)` and recompute every
+    /// point, in dependency order, that derives from it — turning a one-shot
+    /// construction into a live, draggable one. Errors if `point_id` names a
+    /// constructed point (which is derived, not dragged) or if recomputation
+    /// finds a downstream construction has become degenerate.
+    pub fn move_point(&mut self, point_id: &str, x: f64, y: f64) -> Result<()> {
+        let point = self.get_point(point_id).ok_or_else(|| GeometryError::PointNotFound {
+            id: point_id.to_string(),
+        })?;
+        if point.is_constructed {
+            return Err(GeometryError::InvalidConstruction {
+                reason: format!("point {} is constructed and cannot be moved directly", point_id),
+            });
+        }
+
+        self.set_point_position(point_id, x, y)?;
+        crate::construction_graph::recompute_dependents(self, point_id)
+    }
+
     /// Validate a construction step
     pub fn validate_step(&self, step: &ConstructionStep) -> bool {
         match step {
             ConstructionStep::AddPoint { .. } => true, // Always valid
             ConstructionStep::ConstructLine { point1_id, point2_id, .. } => {
-                self.points.contains_key(point1_id) && 
-                self.points.contains_key(point2_id) &&
+                self.point_ids.contains_key(point1_id) &&
+                self.point_ids.contains_key(point2_id) &&
                 point1_id != point2_id
             }
             ConstructionStep::ConstructCircle { center_id, radius_point_id, .. } => {
-                self.points.contains_key(center_id) && 
-                self.points.contains_key(radius_point_id) &&
+                self.point_ids.contains_key(center_id) &&
+                self.point_ids.contains_key(radius_point_id) &&
                 center_id != radius_point_id
             }
             ConstructionStep::FindIntersections { obj1_id, obj2_id } => {
-                (self.lines.contains_key(obj1_id) || self.circles.contains_key(obj1_id)) &&
-                (self.lines.contains_key(obj2_id) || self.circles.contains_key(obj2_id))
+                (self.line_ids.contains_key(obj1_id) || self.circle_ids.contains_key(obj1_id)) &&
+                (self.line_ids.contains_key(obj2_id) || self.circle_ids.contains_key(obj2_id))
             }
         }
     }
@@ -187,19 +401,19 @@ impl ConstructionSpace {
     /// Get all objects in the construction space
     pub fn get_all_objects(&self) -> Vec<GeometricObject> {
         let mut objects = Vec::new();
-        
-        for point in self.points.values() {
+
+        for point in self.points() {
             objects.push(GeometricObject::Point(point.clone()));
         }
-        
-        for line in self.lines.values() {
+
+        for line in self.lines() {
             objects.push(GeometricObject::Line(line.clone()));
         }
-        
-        for circle in self.circles.values() {
+
+        for circle in self.circles() {
             objects.push(GeometricObject::Circle(circle.clone()));
         }
-        
+
         objects
     }
 
@@ -208,32 +422,412 @@ impl ConstructionSpace {
         self.points.clear();
         self.lines.clear();
         self.circles.clear();
+        self.point_ids.clear();
+        self.line_ids.clear();
+        self.circle_ids.clear();
         self.history.clear();
     }
 
     /// Get the number of objects in the construction
     pub fn object_count(&self) -> usize {
-        self.points.len() + self.lines.len() + self.circles.len()
+        self.point_count() + self.line_count() + self.circle_count()
+    }
+
+    /// The smallest circle covering every point currently in the space,
+    /// found with Welzl's randomized incremental algorithm. Useful for
+    /// auto-framing a figure or bounding a locus. The center and a point on
+    /// the boundary are registered as real points (so the result behaves
+    /// like any other constructed `Circle`); `None` only when there are no
+    /// points to enclose.
+    pub fn minimum_enclosing_circle(&mut self) -> Option<Circle> {
+        let points: Vec<Point2<f64>> = self.points().map(|p| p.position).collect();
+        if points.is_empty() {
+            return None;
+        }
+
+        // Shuffle with a seed derived from the point count: deterministic,
+        // so the same construction always frames the same way, while still
+        // giving Welzl's algorithm its expected linear-time random order
+        let mut shuffled = points;
+        let mut rng = crate::encounter::SplitMix64::new(shuffled.len() as u64 ^ 0x6D45_4331_5765_6C7A);
+        for i in (1..shuffled.len()).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            shuffled.swap(i, j);
+        }
+
+        let (center, radius) = welzl(&shuffled, &mut Vec::new())?;
+
+        let center_point = Point::constructed(center.x, center.y, None, Vec::new());
+        let radius_point = Point::constructed(center.x + radius, center.y, None, Vec::new());
+        let center_id = self.add_point(center_point);
+        let radius_id = self.add_point(radius_point);
+
+        self.construct_circle(&center_id, &radius_id, Some("MEC".to_string()))
+            .ok()
+            .and_then(|circle_id| self.get_circle(&circle_id).cloned())
+    }
+
+    /// The Delaunay triangulation of every point currently in the space,
+    /// with the edge-adjacency map needed to walk neighboring triangles.
+    /// Delegates to the same incremental insert-and-flip `delaunay` used by
+    /// `GeometryEngine::triangulate`, just without catching the triangles as
+    /// collection elements.
+    pub fn triangulate(&self) -> crate::triangulation::Triangulation {
+        let points: Vec<Point2<f64>> = self.points().map(|p| p.position).collect();
+        crate::triangulation::delaunay(&points)
+    }
+
+    /// Whether `id` currently names a point, line, or circle in the space
+    fn object_exists(&self, id: &str) -> bool {
+        self.point_ids.contains_key(id) || self.line_ids.contains_key(id) || self.circle_ids.contains_key(id)
+    }
+
+    /// Drop `id` from whichever slab and lookup map it lives in
+    fn remove_object(&mut self, id: &str) {
+        if let Some(handle) = self.point_ids.remove(id) {
+            self.points.remove(handle);
+            self.derivations.remove(id);
+        } else if let Some(handle) = self.line_ids.remove(id) {
+            self.lines.remove(handle);
+        } else if let Some(handle) = self.circle_ids.remove(id) {
+            self.circles.remove(handle);
+        }
+    }
+
+    /// Apply an already-validated step, preserving the ids embedded in it
+    /// (unlike `add_point`/`construct_line`/`construct_circle`, which mint
+    /// fresh ones) — used by `replay` to reproduce a space exactly.
+    fn apply_step(&mut self, step: ConstructionStep) {
+        match &step {
+            ConstructionStep::AddPoint { point, derivation } => {
+                let handle = self.points.insert(point.clone());
+                self.point_ids.insert(point.id.clone(), handle);
+                if let Some(derivation) = derivation {
+                    self.derivations.insert(point.id.clone(), derivation.clone());
+                }
+            }
+            ConstructionStep::ConstructLine { line, .. } => {
+                let handle = self.lines.insert(line.clone());
+                self.line_ids.insert(line.id.clone(), handle);
+            }
+            ConstructionStep::ConstructCircle { circle, .. } => {
+                let handle = self.circles.insert(circle.clone());
+                self.circle_ids.insert(circle.id.clone(), handle);
+            }
+            ConstructionStep::FindIntersections { .. } => {}
+        }
+        self.history.push(step);
+    }
+
+    /// Undo a construction step by removing `object_id` and everything
+    /// downstream of it (lines and circles built on it, points intersected
+    /// from it, and so on), keeping the space and its `history` consistent
+    /// with each other.
+    pub fn undo(&mut self, object_id: &str) -> Result<()> {
+        if !self.object_exists(object_id) {
+            return Err(GeometryError::InvalidConstruction {
+                reason: format!("object {} not found", object_id),
+            });
+        }
+
+        let mut to_remove = crate::construction_graph::downstream_of(self, object_id);
+        to_remove.insert(object_id.to_string());
+
+        self.history.retain(|step| match step.produces() {
+            Some(id) => !to_remove.contains(&id),
+            None => true,
+        });
+
+        for id in &to_remove {
+            self.remove_object(id);
+        }
+
+        Ok(())
+    }
+
+    /// Every object whose recorded dependencies no longer exist in the
+    /// space — should normally be empty, since `undo` cascades cleanly, but
+    /// catches inconsistencies left by hand-edited or partially replayed history.
+    pub fn find_orphans(&self) -> Vec<String> {
+        self.get_all_objects()
+            .into_iter()
+            .filter(|obj| obj.dependencies().iter().any(|dep| !self.object_exists(dep)))
+            .map(|obj| obj.id().to_string())
+            .collect()
+    }
+
+    /// The perpendicular bisector of segment `p1_id`-`p2_id`, built the
+    /// classical way: two circles, each centered on one point with radius
+    /// `|p1 p2|`, cross at two points equidistant from both `p1` and `p2`,
+    /// and the line through those two points is the bisector.
+    pub fn construct_perpendicular_bisector(&mut self, p1_id: &str, p2_id: &str) -> Result<String> {
+        let circle1 = self.construct_circle(p1_id, p2_id, None)?;
+        let circle2 = self.construct_circle(p2_id, p1_id, None)?;
+
+        let intersections = self.find_intersections(&circle1, &circle2)?;
+        if intersections.len() < 2 {
+            return Err(GeometryError::InvalidConstruction {
+                reason: "circles centered on p1 and p2 do not cross in two points".to_string(),
+            });
+        }
+
+        self.construct_line(&intersections[0].id, &intersections[1].id, Some("perpendicular_bisector".to_string()))
     }
+
+    /// The bisector of angle `a`-`vertex`-`b`: a circle centered on `vertex`
+    /// marks a point equidistant from `vertex` on each of the two rays, and
+    /// `vertex` itself is equidistant from those two points, so it lies on
+    /// (and the bisector direction is along) their perpendicular bisector.
+    pub fn construct_angle_bisector(&mut self, a_id: &str, vertex_id: &str, b_id: &str) -> Result<String> {
+        let a = self.get_point(a_id).cloned().ok_or_else(|| GeometryError::PointNotFound { id: a_id.to_string() })?;
+        let vertex = self.get_point(vertex_id).cloned().ok_or_else(|| GeometryError::PointNotFound { id: vertex_id.to_string() })?;
+        let b = self.get_point(b_id).cloned().ok_or_else(|| GeometryError::PointNotFound { id: b_id.to_string() })?;
+
+        let dist_a = vertex.distance_to(&a);
+        let dist_b = vertex.distance_to(&b);
+        if dist_a < EPSILON || dist_b < EPSILON {
+            return Err(GeometryError::InvalidConstruction {
+                reason: "vertex coincides with a or b".to_string(),
+            });
+        }
+
+        // Radius from whichever of a/b is closer to vertex, so the circle
+        // crosses both rays rather than overshooting the shorter one
+        let radius_ref = if dist_a <= dist_b { a_id } else { b_id };
+        let circle = self.construct_circle(vertex_id, radius_ref, None)?;
+        let line_va = self.construct_line(vertex_id, a_id, None)?;
+        let line_vb = self.construct_line(vertex_id, b_id, None)?;
+
+        let dir_a = a.position - vertex.position;
+        let dir_b = b.position - vertex.position;
+
+        let side_a = self
+            .find_intersections(&circle, &line_va)?
+            .into_iter()
+            .find(|p| (p.position - vertex.position).dot(&dir_a) > 0.0)
+            .ok_or_else(|| GeometryError::InvalidConstruction {
+                reason: "circle does not meet ray vertex->a".to_string(),
+            })?;
+        let side_b = self
+            .find_intersections(&circle, &line_vb)?
+            .into_iter()
+            .find(|p| (p.position - vertex.position).dot(&dir_b) > 0.0)
+            .ok_or_else(|| GeometryError::InvalidConstruction {
+                reason: "circle does not meet ray vertex->b".to_string(),
+            })?;
+
+        self.construct_perpendicular_bisector(&side_a.id, &side_b.id)
+    }
+
+    /// A line's Cartesian coefficients `(a, b)` in `ax + by + c = 0` form,
+    /// read straight off its two defining points' coordinates. No division
+    /// is involved, so a vertical or very short line is exactly as robust
+    /// as any other.
+    pub(crate) fn line_cartesian_ab(&self, line_id: &str) -> Result<(f64, f64)> {
+        let line = self.get_line(line_id).ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("line {} not found", line_id),
+        })?;
+        let p1 = self.get_point(&line.point1_id).unwrap();
+        let p2 = self.get_point(&line.point2_id).unwrap();
+        Ok((p2.position.y - p1.position.y, p1.position.x - p2.position.x))
+    }
+
+    /// A new point offset from `through_point_id` along `line_id`'s direction
+    /// (its normal, if `perpendicular`), and the line through both. Shared
+    /// tail end of `construct_parallel` and `construct_perpendicular`.
+    fn construct_translated_line(&mut self, line_id: &str, through_point_id: &str, perpendicular: bool, label: &str) -> Result<String> {
+        let through = self.get_point(through_point_id).cloned().ok_or_else(|| GeometryError::PointNotFound {
+            id: through_point_id.to_string(),
+        })?;
+        let line = self.get_line(line_id).cloned().ok_or_else(|| GeometryError::InvalidConstruction {
+            reason: format!("line {} not found", line_id),
+        })?;
+        let direction = translated_direction(self, line_id, perpendicular)?;
+
+        let second_point = Point::constructed(
+            through.position.x + direction.x,
+            through.position.y + direction.y,
+            None,
+            vec![through_point_id.to_string(), line.point1_id.clone(), line.point2_id.clone()],
+        );
+        let second_id = self.add_point_with_derivation(
+            second_point,
+            Some(Derivation::OffsetFromLine {
+                line_id: line_id.to_string(),
+                through_point_id: through_point_id.to_string(),
+                perpendicular,
+            }),
+        );
+
+        self.construct_line(through_point_id, &second_id, Some(label.to_string()))
+    }
+
+    /// The line through `through_point_id` parallel to `line_id`: reusing
+    /// `line_id`'s own direction `(-b, a)` unchanged, anchored at the new point.
+    pub fn construct_parallel(&mut self, line_id: &str, through_point_id: &str) -> Result<String> {
+        self.construct_translated_line(line_id, through_point_id, false, "parallel")
+    }
+
+    /// The line through `through_point_id` perpendicular to `line_id`: using
+    /// `line_id`'s own Cartesian normal `(a, b)` as the new direction.
+    pub fn construct_perpendicular(&mut self, line_id: &str, through_point_id: &str) -> Result<String> {
+        self.construct_translated_line(line_id, through_point_id, true, "perpendicular")
+    }
+}
+
+/// `line_id`'s current direction `(-b, a)` (or its normal `(a, b)`, if
+/// `perpendicular`), read fresh off its defining points every call so a
+/// `Derivation::OffsetFromLine` replay stays parallel/perpendicular even
+/// after `line_id`'s own points have moved.
+pub(crate) fn translated_direction(space: &ConstructionSpace, line_id: &str, perpendicular: bool) -> Result<Vector2<f64>> {
+    let (a, b) = space.line_cartesian_ab(line_id)?;
+    let direction = if perpendicular { Vector2::new(a, b) } else { Vector2::new(-b, a) };
+    if crate::ops::norm(direction) < EPSILON {
+        return Err(GeometryError::InvalidConstruction {
+            reason: "reference line has zero length".to_string(),
+        });
+    }
+    Ok(direction)
+}
+
+/// The smallest circle through zero, one, two, or three boundary points —
+/// the base case of Welzl's recursion
+fn trivial_circle(boundary: &[Point2<f64>]) -> Option<(Point2<f64>, f64)> {
+    match boundary.len() {
+        0 => None,
+        1 => Some((boundary[0], 0.0)),
+        2 => Some(circle_from_diameter(boundary[0], boundary[1])),
+        3 => circumcenter_raw(boundary[0], boundary[1], boundary[2]).or_else(|| {
+            // (Near-)collinear triple: no circumcircle, so fall back to the
+            // diameter of the two farthest of the three points
+            let pairs = [(0, 1), (0, 2), (1, 2)];
+            let (i, j) = pairs
+                .into_iter()
+                .max_by(|&(a1, b1), &(a2, b2)| {
+                    let d1 = crate::ops::distance(boundary[a1], boundary[b1]);
+                    let d2 = crate::ops::distance(boundary[a2], boundary[b2]);
+                    d1.partial_cmp(&d2).unwrap()
+                })
+                .unwrap();
+            Some(circle_from_diameter(boundary[i], boundary[j]))
+        }),
+        _ => unreachable!("welzl never grows the boundary set past 3 points"),
+    }
+}
+
+/// The circle with `a`-`b` as its diameter
+fn circle_from_diameter(a: Point2<f64>, b: Point2<f64>) -> (Point2<f64>, f64) {
+    let center = Point2::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+    (center, crate::ops::distance(a, center))
+}
+
+/// The circumcircle of three points, or `None` if they're (near-)collinear
+fn circumcenter_raw(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> Option<(Point2<f64>, f64)> {
+    let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+    if d.abs() < EPSILON {
+        return None;
+    }
+
+    let ax2ay2 = a.x * a.x + a.y * a.y;
+    let bx2by2 = b.x * b.x + b.y * b.y;
+    let cx2cy2 = c.x * c.x + c.y * c.y;
+
+    let ux = (ax2ay2 * (b.y - c.y) + bx2by2 * (c.y - a.y) + cx2cy2 * (a.y - b.y)) / d;
+    let uy = (ax2ay2 * (c.x - b.x) + bx2by2 * (a.x - c.x) + cx2cy2 * (b.x - a.x)) / d;
+
+    let center = Point2::new(ux, uy);
+    Some((center, crate::ops::distance(a, center)))
+}
+
+/// Welzl's randomized incremental minimum enclosing circle: `points` is the
+/// (already shuffled) remaining candidates to place, `boundary` the points
+/// known to lie exactly on the circle's edge so far. Expects linear time
+/// over the shuffled order.
+fn welzl(points: &[Point2<f64>], boundary: &mut Vec<Point2<f64>>) -> Option<(Point2<f64>, f64)> {
+    if points.is_empty() || boundary.len() == 3 {
+        return trivial_circle(boundary);
+    }
+
+    let (&p, rest) = points.split_last().unwrap();
+    let circle = welzl(rest, boundary);
+
+    match circle {
+        Some((center, radius)) if crate::ops::distance(center, p) <= radius + EPSILON => circle,
+        _ => {
+            boundary.push(p);
+            let result = welzl(rest, boundary);
+            boundary.pop();
+            result
+        }
+    }
+}
+
+/// How two circles relate to each other, classified by `classify_circles`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircleRelationship {
+    /// Same center and radius
+    Equal,
+    /// The first circle lies entirely inside the second
+    Contained,
+    /// The first circle entirely contains the second
+    Contains,
+    /// The circles touch at exactly one point
+    Tangent,
+    /// The circles cross at this many points (always 2)
+    Intersecting(u8),
+    /// The circles don't touch at all
+    Disjoint,
+}
+
+/// Records how a constructed point was derived, so `ConstructionGraph` can
+/// replay the same operation when one of its inputs moves
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Derivation {
+    /// The `index`-th point returned by intersecting `obj1_id` and `obj2_id`
+    /// (each a line or circle ID)
+    Intersection {
+        obj1_id: String,
+        obj2_id: String,
+        index: usize,
+    },
+    /// Offset from `through_point_id` along `line_id`'s current direction
+    /// (negated/rotated to its normal when `perpendicular`) — how
+    /// `construct_translated_line` places its second point for
+    /// `construct_parallel`/`construct_perpendicular`. The direction is
+    /// recomputed from `line_id`'s live Cartesian form on every replay,
+    /// rather than frozen at construction time, so dragging either of
+    /// `line_id`'s own defining points keeps the offset line parallel or
+    /// perpendicular to it.
+    OffsetFromLine {
+        line_id: String,
+        through_point_id: String,
+        perpendicular: bool,
+    },
 }
 
 /// Represents a single construction step that can be replayed
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ConstructionStep {
-    AddPoint { 
-        point: Point 
+    AddPoint {
+        point: Point,
+        /// How this point was derived, if it came from `find_intersections`
+        /// or a parallel/perpendicular construction rather than a bare
+        /// `add_point` — carried so `replay` can restore
+        /// `derivations` and keep the replayed space just as draggable as
+        /// the original.
+        derivation: Option<Derivation>,
     },
-    ConstructLine { 
+    ConstructLine {
         line: Line,
         point1_id: String,
         point2_id: String,
     },
-    ConstructCircle { 
+    ConstructCircle {
         circle: Circle,
         center_id: String,
         radius_point_id: String,
     },
-    FindIntersections { 
+    FindIntersections {
         obj1_id: String,
         obj2_id: String,
     },
@@ -265,6 +859,79 @@ impl ConstructionStep {
             }
         }
     }
+
+    /// The id of the object this step adds to the space, if any. A
+    /// `FindIntersections` step is historical metadata only — each point it
+    /// found is recorded by its own `AddPoint` step, so it produces nothing
+    /// by itself and nothing can depend on it directly.
+    pub fn produces(&self) -> Option<String> {
+        match self {
+            ConstructionStep::AddPoint { point, .. } => Some(point.id.clone()),
+            ConstructionStep::ConstructLine { line, .. } => Some(line.id.clone()),
+            ConstructionStep::ConstructCircle { circle, .. } => Some(circle.id.clone()),
+            ConstructionStep::FindIntersections { .. } => None,
+        }
+    }
+}
+
+/// Rebuild a fresh `ConstructionSpace` by re-executing `history`'s steps in
+/// dependency order (so hand-edited or concatenated history that isn't
+/// already topologically sorted still replays correctly), validating each
+/// with `validate_step` before applying it. Errors on the first step that
+/// turns out to be inconsistent, e.g. a line whose endpoint was never added.
+pub fn replay(history: &[ConstructionStep]) -> Result<ConstructionSpace> {
+    let ordered = topological_step_order(history)?;
+    let mut space = ConstructionSpace::new();
+
+    for step in ordered {
+        if !space.validate_step(&step) {
+            return Err(GeometryError::InvalidConstruction {
+                reason: format!("inconsistent construction step: {:?}", step),
+            });
+        }
+        space.apply_step(step);
+    }
+
+    Ok(space)
+}
+
+/// Kahn's algorithm over a raw (possibly out-of-order) step list, keyed by
+/// each step's `produces()` id, mirroring `construction_graph::topological_order`'s
+/// approach to the same problem over a live space's objects.
+fn topological_step_order(history: &[ConstructionStep]) -> Result<Vec<ConstructionStep>> {
+    let produced: HashSet<String> = history.iter().filter_map(|step| step.produces()).collect();
+
+    let mut remaining: Vec<usize> = (0..history.len()).collect();
+    let mut placed: HashSet<String> = HashSet::new();
+    let mut ordered = Vec::new();
+
+    while !remaining.is_empty() {
+        let ready: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|&i| {
+                history[i]
+                    .dependencies()
+                    .iter()
+                    .all(|d| placed.contains(d) || !produced.contains(d))
+            })
+            .collect();
+
+        if ready.is_empty() {
+            return Err(GeometryError::GraphError("dependency cycle detected in history".to_string()));
+        }
+
+        let ready: HashSet<usize> = ready.into_iter().collect();
+        for &i in &ready {
+            if let Some(id) = history[i].produces() {
+                placed.insert(id);
+            }
+            ordered.push(history[i].clone());
+        }
+        remaining.retain(|i| !ready.contains(i));
+    }
+
+    Ok(ordered)
 }
 
 #[cfg(test)]
@@ -274,9 +941,9 @@ mod tests {
     #[test]
     fn test_construction_space_new() {
         let space = ConstructionSpace::new();
-        assert_eq!(space.points.len(), 0);
-        assert_eq!(space.lines.len(), 0);
-        assert_eq!(space.circles.len(), 0);
+        assert_eq!(space.point_count(), 0);
+        assert_eq!(space.line_count(), 0);
+        assert_eq!(space.circle_count(), 0);
         assert_eq!(space.history.len(), 0);
     }
 
@@ -285,32 +952,32 @@ mod tests {
         let mut space = ConstructionSpace::new();
         let point = Point::new(1.0, 2.0, Some("A".to_string()));
         let id = space.add_point(point.clone());
-        
-        assert_eq!(space.points.len(), 1);
+
+        assert_eq!(space.point_count(), 1);
         assert_eq!(space.history.len(), 1);
-        assert!(space.points.contains_key(&id));
+        assert!(space.get_point(&id).is_some());
     }
 
     #[test]
     fn test_construct_line() {
         let mut space = ConstructionSpace::new();
-        
+
         let point1 = Point::new(0.0, 0.0, Some("A".to_string()));
         let point2 = Point::new(1.0, 1.0, Some("B".to_string()));
         let id1 = space.add_point(point1);
         let id2 = space.add_point(point2);
-        
+
         let line_id = space.construct_line(&id1, &id2, Some("AB".to_string())).unwrap();
-        
-        assert_eq!(space.lines.len(), 1);
-        assert!(space.lines.contains_key(&line_id));
+
+        assert_eq!(space.line_count(), 1);
+        assert!(space.get_line(&line_id).is_some());
         assert_eq!(space.history.len(), 3); // 2 points + 1 line
     }
 
     #[test]
     fn test_construct_line_invalid_points() {
         let mut space = ConstructionSpace::new();
-        
+
         let result = space.construct_line("invalid1", "invalid2", None);
         assert!(result.is_err());
     }
@@ -318,34 +985,34 @@ mod tests {
     #[test]
     fn test_construct_circle() {
         let mut space = ConstructionSpace::new();
-        
+
         let center = Point::new(0.0, 0.0, Some("O".to_string()));
         let radius_point = Point::new(1.0, 0.0, Some("A".to_string()));
         let center_id = space.add_point(center);
         let radius_id = space.add_point(radius_point);
-        
+
         let circle_id = space.construct_circle(&center_id, &radius_id, Some("Circle".to_string())).unwrap();
-        
-        assert_eq!(space.circles.len(), 1);
-        assert!(space.circles.contains_key(&circle_id));
+
+        assert_eq!(space.circle_count(), 1);
+        assert!(space.get_circle(&circle_id).is_some());
         assert_eq!(space.history.len(), 3); // 2 points + 1 circle
     }
 
     #[test]
     fn test_line_line_intersection() {
         let mut space = ConstructionSpace::new();
-        
+
         // Create two intersecting lines
         let p1 = space.add_point(Point::new(0.0, 0.0, None));
         let p2 = space.add_point(Point::new(2.0, 0.0, None));
         let p3 = space.add_point(Point::new(1.0, -1.0, None));
         let p4 = space.add_point(Point::new(1.0, 1.0, None));
-        
+
         let line1 = space.construct_line(&p1, &p2, None).unwrap();
         let line2 = space.construct_line(&p3, &p4, None).unwrap();
-        
+
         let intersections = space.find_intersections(&line1, &line2).unwrap();
-        
+
         assert_eq!(intersections.len(), 1);
         assert!((intersections[0].position.x - 1.0).abs() < 1e-10);
         assert!((intersections[0].position.y - 0.0).abs() < 1e-10);
@@ -358,21 +1025,561 @@ mod tests {
         let point2 = Point::new(1.0, 1.0, None);
         let id1 = space.add_point(point1.clone());
         let id2 = space.add_point(point2.clone());
-        
+
         let line = Line::new(id1.clone(), id2.clone(), None);
         let valid_step = ConstructionStep::ConstructLine {
             line: line.clone(),
             point1_id: id1.clone(),
             point2_id: id2.clone(),
         };
-        
+
         let invalid_step = ConstructionStep::ConstructLine {
             line,
             point1_id: "invalid".to_string(),
             point2_id: id2,
         };
-        
+
         assert!(space.validate_step(&valid_step));
         assert!(!space.validate_step(&invalid_step));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_classify_circles_equal() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(1.0, 0.0, None));
+        let c2 = space.add_point(Point::new(0.0, 0.0, None));
+        let r2 = space.add_point(Point::new(0.0, 1.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+
+        assert_eq!(space.classify_circles(&circle1, &circle2).unwrap(), CircleRelationship::Equal);
+    }
+
+    #[test]
+    fn test_classify_circles_tangent() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(1.0, 0.0, None));
+        let c2 = space.add_point(Point::new(2.0, 0.0, None));
+        let r2 = space.add_point(Point::new(3.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+
+        assert_eq!(space.classify_circles(&circle1, &circle2).unwrap(), CircleRelationship::Tangent);
+    }
+
+    #[test]
+    fn test_classify_circles_intersecting_and_contained() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(2.0, 0.0, None));
+        let c2 = space.add_point(Point::new(1.0, 0.0, None));
+        let r2 = space.add_point(Point::new(3.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+        assert_eq!(space.classify_circles(&circle1, &circle2).unwrap(), CircleRelationship::Intersecting(2));
+
+        let c3 = space.add_point(Point::new(0.0, 0.0, None));
+        let r3 = space.add_point(Point::new(0.5, 0.0, None));
+        let circle3 = space.construct_circle(&c3, &r3, None).unwrap();
+        assert_eq!(space.classify_circles(&circle1, &circle3).unwrap(), CircleRelationship::Contains);
+        assert_eq!(space.classify_circles(&circle3, &circle1).unwrap(), CircleRelationship::Contained);
+    }
+
+    #[test]
+    fn test_classify_circles_disjoint() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(1.0, 0.0, None));
+        let c2 = space.add_point(Point::new(100.0, 0.0, None));
+        let r2 = space.add_point(Point::new(101.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+
+        assert_eq!(space.classify_circles(&circle1, &circle2).unwrap(), CircleRelationship::Disjoint);
+    }
+
+    #[test]
+    fn test_find_intersections_tangent_circles_yields_single_point() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(1.0, 0.0, None));
+        let c2 = space.add_point(Point::new(2.0, 0.0, None));
+        let r2 = space.add_point(Point::new(3.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+
+        let intersections = space.find_intersections(&circle1, &circle2).unwrap();
+        assert_eq!(intersections.len(), 1);
+    }
+
+    #[test]
+    fn test_find_intersections_contained_circles_yields_no_points() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let r1 = space.add_point(Point::new(5.0, 0.0, None));
+        let c2 = space.add_point(Point::new(0.0, 0.0, None));
+        let r2 = space.add_point(Point::new(1.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &r1, None).unwrap();
+        let circle2 = space.construct_circle(&c2, &r2, None).unwrap();
+
+        let intersections = space.find_intersections(&circle1, &circle2).unwrap();
+        assert!(intersections.is_empty());
+    }
+
+    #[test]
+    fn test_construct_segment_and_ray_have_expected_bounds() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(1.0, 0.0, None));
+
+        let segment_id = space.construct_segment(&p1, &p2, None).unwrap();
+        let ray_id = space.construct_ray(&p1, &p2, None).unwrap();
+
+        assert_eq!(space.get_line(&segment_id).unwrap().bounds, Boundedness::Segment);
+        assert_eq!(space.get_line(&ray_id).unwrap().bounds, Boundedness::Ray);
+    }
+
+    #[test]
+    fn test_find_intersections_respects_segment_bounds() {
+        let mut space = ConstructionSpace::new();
+
+        // Same crossing configuration as test_line_line_intersection, but the
+        // first pair only spans x in [0, 0.5] — short of where the (infinite)
+        // lines would actually cross at x = 1.0
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(0.5, 0.0, None));
+        let p3 = space.add_point(Point::new(1.0, -1.0, None));
+        let p4 = space.add_point(Point::new(1.0, 1.0, None));
+
+        let segment = space.construct_segment(&p1, &p2, None).unwrap();
+        let line = space.construct_line(&p3, &p4, None).unwrap();
+
+        let intersections = space.find_intersections(&segment, &line).unwrap();
+        assert!(intersections.is_empty());
+    }
+
+    #[test]
+    fn test_classify_segments_crossing_point() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(2.0, 0.0, None));
+        let p3 = space.add_point(Point::new(1.0, -1.0, None));
+        let p4 = space.add_point(Point::new(1.0, 1.0, None));
+
+        let line1 = space.construct_segment(&p1, &p2, None).unwrap();
+        let line2 = space.construct_segment(&p3, &p4, None).unwrap();
+
+        match space.classify_segments(&line1, &line2).unwrap() {
+            Intersection::Point(p) => {
+                assert!((p.x - 1.0).abs() < 1e-10);
+                assert!((p.y - 0.0).abs() < 1e-10);
+            }
+            other => panic!("expected a crossing point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_segments_collinear_overlap() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(2.0, 0.0, None));
+        let p3 = space.add_point(Point::new(1.0, 0.0, None));
+        let p4 = space.add_point(Point::new(3.0, 0.0, None));
+
+        let line1 = space.construct_segment(&p1, &p2, None).unwrap();
+        let line2 = space.construct_segment(&p3, &p4, None).unwrap();
+
+        match space.classify_segments(&line1, &line2).unwrap() {
+            Intersection::Overlap(a, b) => {
+                let (lo, hi) = (a.x.min(b.x), a.x.max(b.x));
+                assert!((lo - 1.0).abs() < 1e-10);
+                assert!((hi - 2.0).abs() < 1e-10);
+            }
+            other => panic!("expected an overlap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_minimum_enclosing_circle_empty_space_is_none() {
+        let space = ConstructionSpace::new();
+        assert!(space.clone().minimum_enclosing_circle().is_none());
+    }
+
+    #[test]
+    fn test_minimum_enclosing_circle_single_point_has_zero_radius() {
+        let mut space = ConstructionSpace::new();
+        let id = space.add_point(Point::new(3.0, 4.0, None));
+
+        let circle = space.minimum_enclosing_circle().unwrap();
+        let center = space.get_point(&circle.center_id).unwrap();
+        let radius_point = space.get_point(&circle.radius_point_id).unwrap();
+
+        assert!((center.position.x - 3.0).abs() < 1e-9);
+        assert!((center.position.y - 4.0).abs() < 1e-9);
+        assert!(circle.radius(center, radius_point) < 1e-9);
+        assert!(space.get_point(&id).is_some());
+    }
+
+    #[test]
+    fn test_minimum_enclosing_circle_square_is_centered_with_diagonal_radius() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        space.add_point(Point::new(4.0, 0.0, None));
+        space.add_point(Point::new(4.0, 4.0, None));
+        space.add_point(Point::new(0.0, 4.0, None));
+
+        let circle = space.minimum_enclosing_circle().unwrap();
+        let center = space.get_point(&circle.center_id).unwrap();
+        let radius_point = space.get_point(&circle.radius_point_id).unwrap();
+
+        assert!((center.position.x - 2.0).abs() < 1e-9);
+        assert!((center.position.y - 2.0).abs() < 1e-9);
+        assert!((circle.radius(center, radius_point) - 2.0 * std::f64::consts::SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimum_enclosing_circle_covers_every_point() {
+        let mut space = ConstructionSpace::new();
+        let positions = [
+            (0.0, 0.0), (5.0, 1.0), (2.0, 6.0), (-3.0, 2.0), (1.0, -4.0), (1.0, 1.0),
+        ];
+        for (x, y) in positions {
+            space.add_point(Point::new(x, y, None));
+        }
+
+        let circle = space.minimum_enclosing_circle().unwrap();
+        let center = space.get_point(&circle.center_id).unwrap();
+        let radius_point = space.get_point(&circle.radius_point_id).unwrap();
+        let radius = circle.radius(center, radius_point);
+
+        for (x, y) in positions {
+            let d = ((x - center.position.x).powi(2) + (y - center.position.y).powi(2)).sqrt();
+            assert!(d <= radius + 1e-9, "point ({x}, {y}) at distance {d} escapes radius {radius}");
+        }
+    }
+
+    #[test]
+    fn test_minimum_enclosing_circle_handles_collinear_and_duplicate_points() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        space.add_point(Point::new(1.0, 0.0, None));
+        space.add_point(Point::new(2.0, 0.0, None));
+        space.add_point(Point::new(1.0, 0.0, None)); // duplicate
+
+        let circle = space.minimum_enclosing_circle().unwrap();
+        let center = space.get_point(&circle.center_id).unwrap();
+        let radius_point = space.get_point(&circle.radius_point_id).unwrap();
+
+        assert!((center.position.x - 1.0).abs() < 1e-9);
+        assert!((center.position.y - 0.0).abs() < 1e-9);
+        assert!((circle.radius(center, radius_point) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangulate_square_yields_two_triangles_with_shared_edge() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        space.add_point(Point::new(1.0, 0.0, None));
+        space.add_point(Point::new(1.0, 1.0, None));
+        space.add_point(Point::new(0.0, 1.0, None));
+
+        let result = space.triangulate();
+        assert_eq!(result.triangles.len(), 2);
+
+        let shared_edges = result
+            .adjacency
+            .values()
+            .filter(|(t0, t1)| t0.is_some() && t1.is_some())
+            .count();
+        assert_eq!(shared_edges, 1);
+    }
+
+    #[test]
+    fn test_triangulate_too_few_points_yields_no_triangles() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        space.add_point(Point::new(1.0, 0.0, None));
+
+        let result = space.triangulate();
+        assert!(result.triangles.is_empty());
+    }
+
+    #[test]
+    fn test_handle_reuse_keeps_remaining_points_stable() {
+        let mut space = ConstructionSpace::new();
+        let id1 = space.add_point(Point::new(0.0, 0.0, None));
+        let id2 = space.add_point(Point::new(1.0, 1.0, None));
+
+        // Removing and re-adding should not disturb the untouched point
+        space.undo(&id1).unwrap();
+        let id3 = space.add_point(Point::new(5.0, 5.0, None));
+        assert!(space.get_point(&id1).is_none());
+        assert_eq!(space.get_point(&id2).unwrap().position.x, 1.0);
+        assert_eq!(space.get_point(&id2).unwrap().position.y, 1.0);
+        assert_eq!(space.get_point(&id3).unwrap().position.x, 5.0);
+    }
+
+    #[test]
+    fn test_undo_removes_object_and_its_dependents() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(1.0, 1.0, None));
+        let p3 = space.add_point(Point::new(2.0, 0.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+        let circle = space.construct_circle(&p1, &p3, None).unwrap();
+
+        space.undo(&p1).unwrap();
+
+        assert!(space.get_point(&p1).is_none());
+        assert!(space.get_line(&line).is_none());
+        assert!(space.get_circle(&circle).is_none());
+        // Untouched points survive
+        assert!(space.get_point(&p2).is_some());
+        assert!(space.get_point(&p3).is_some());
+        assert!(space.find_orphans().is_empty());
+    }
+
+    #[test]
+    fn test_undo_leaves_unrelated_objects_intact() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(1.0, 1.0, None));
+        let p3 = space.add_point(Point::new(5.0, 5.0, None));
+        let p4 = space.add_point(Point::new(6.0, 6.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+        let unrelated_line = space.construct_line(&p3, &p4, None).unwrap();
+
+        space.undo(&p1).unwrap();
+
+        assert!(space.get_line(&line).is_none());
+        assert!(space.get_line(&unrelated_line).is_some());
+    }
+
+    #[test]
+    fn test_undo_missing_object_errors() {
+        let mut space = ConstructionSpace::new();
+        assert!(space.undo("does-not-exist").is_err());
+    }
+
+    #[test]
+    fn test_find_orphans_detects_dangling_dependency() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(1.0, 1.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+
+        // Bypass `undo`'s cascading cleanup to simulate a dangling reference
+        space.point_ids.remove(&p1);
+
+        assert_eq!(space.find_orphans(), vec![line]);
+    }
+
+    #[test]
+    fn test_replay_rebuilds_an_equivalent_space() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(2.0, 0.0, None));
+        space.construct_line(&p1, &p2, None).unwrap();
+
+        let rebuilt = replay(&space.history).unwrap();
+
+        assert_eq!(rebuilt.point_count(), space.point_count());
+        assert_eq!(rebuilt.line_count(), space.line_count());
+        assert!(rebuilt.get_point(&p1).is_some());
+        assert!(rebuilt.get_point(&p2).is_some());
+        assert!(rebuilt.find_orphans().is_empty());
+    }
+
+    #[test]
+    fn test_replay_preserves_derivations_so_dragging_still_works() {
+        let mut space = ConstructionSpace::new();
+        let c1 = space.add_point(Point::new(0.0, 0.0, None));
+        let c1r = space.add_point(Point::new(1.0, 0.0, None));
+        let circle1 = space.construct_circle(&c1, &c1r, None).unwrap();
+        let c2 = space.add_point(Point::new(2.0, 0.0, None));
+        let c2r = space.add_point(Point::new(3.0, 0.0, None));
+        let circle2 = space.construct_circle(&c2, &c2r, None).unwrap();
+        let intersections = space.find_intersections(&circle1, &circle2).unwrap();
+        assert!(!intersections.is_empty());
+        let intersection_id = intersections[0].id.clone();
+
+        let mut rebuilt = replay(&space.history).unwrap();
+        assert_eq!(rebuilt.derivation(&intersection_id), space.derivation(&intersection_id));
+
+        let before = rebuilt.get_point(&intersection_id).unwrap().position;
+        rebuilt.move_point(&c2, 1.5, 0.0).unwrap();
+        let after = rebuilt.get_point(&intersection_id).unwrap().position;
+        assert_ne!(before, after, "replayed space should still recompute points derived via find_intersections");
+    }
+
+    #[test]
+    fn test_replay_reorders_out_of_order_history() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(2.0, 0.0, None));
+        space.construct_line(&p1, &p2, None).unwrap();
+
+        // Shuffle the recorded steps so the line now comes before its points
+        let mut shuffled = space.history.clone();
+        shuffled.reverse();
+
+        let rebuilt = replay(&shuffled).unwrap();
+        assert_eq!(rebuilt.line_count(), 1);
+        assert_eq!(rebuilt.point_count(), 2);
+    }
+
+    #[test]
+    fn test_replay_surfaces_first_inconsistent_step() {
+        let dangling_line = ConstructionStep::ConstructLine {
+            line: Line::new("missing1".to_string(), "missing2".to_string(), None),
+            point1_id: "missing1".to_string(),
+            point2_id: "missing2".to_string(),
+        };
+
+        assert!(replay(&[dangling_line]).is_err());
+    }
+
+    #[test]
+    fn test_construct_perpendicular_bisector_is_centered_and_perpendicular() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(4.0, 0.0, None));
+
+        let bisector = space.construct_perpendicular_bisector(&p1, &p2).unwrap();
+        let line = space.get_line(&bisector).unwrap();
+        let a = space.get_point(&line.point1_id).unwrap();
+        let b = space.get_point(&line.point2_id).unwrap();
+
+        // Both points on the bisector are equidistant from p1 and p2
+        assert!((a.distance_to(space.get_point(&p1).unwrap()) - a.distance_to(space.get_point(&p2).unwrap())).abs() < 1e-9);
+        assert!((b.distance_to(space.get_point(&p1).unwrap()) - b.distance_to(space.get_point(&p2).unwrap())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_construct_perpendicular_bisector_missing_point_errors() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        assert!(space.construct_perpendicular_bisector(&p1, "missing").is_err());
+    }
+
+    #[test]
+    fn test_construct_angle_bisector_is_equidistant_from_both_rays() {
+        let mut space = ConstructionSpace::new();
+        let vertex = space.add_point(Point::new(0.0, 0.0, None));
+        let a = space.add_point(Point::new(4.0, 0.0, None));
+        let b = space.add_point(Point::new(0.0, 4.0, None));
+
+        let bisector = space.construct_angle_bisector(&a, &vertex, &b).unwrap();
+        let line = space.get_line(&bisector).unwrap();
+        let far_point = space
+            .get_point(&line.point1_id)
+            .filter(|p| p.id != vertex)
+            .or_else(|| space.get_point(&line.point2_id))
+            .unwrap();
+
+        // The 45-degree bisector of the axes runs along y = x
+        assert!((far_point.position.x - far_point.position.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_construct_angle_bisector_rejects_degenerate_vertex() {
+        let mut space = ConstructionSpace::new();
+        let vertex = space.add_point(Point::new(0.0, 0.0, None));
+        let a = space.add_point(Point::new(0.0, 0.0, None));
+        let b = space.add_point(Point::new(0.0, 4.0, None));
+        assert!(space.construct_angle_bisector(&a, &vertex, &b).is_err());
+    }
+
+    #[test]
+    fn test_construct_parallel_has_same_direction_as_original() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(4.0, 2.0, None));
+        let through = space.add_point(Point::new(1.0, 5.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+
+        let parallel = space.construct_parallel(&line, &through).unwrap();
+        let parallel_line = space.get_line(&parallel).unwrap();
+        let other = space.get_point(&parallel_line.point2_id).unwrap();
+        let through_point = space.get_point(&through).unwrap();
+
+        let original_dir = Vector2::new(4.0, 2.0);
+        let new_dir = other.position - through_point.position;
+        // Parallel vectors have zero cross product
+        assert!((original_dir.x * new_dir.y - original_dir.y * new_dir.x).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_construct_parallel_offset_point_follows_through_point_when_dragged() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(4.0, 2.0, None));
+        let through = space.add_point(Point::new(1.0, 5.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+
+        let parallel = space.construct_parallel(&line, &through).unwrap();
+        let parallel_line = space.get_line(&parallel).unwrap().clone();
+        let offset_before = space.get_point(&parallel_line.point2_id).unwrap().position;
+
+        space.move_point(&through, 10.0, -3.0).unwrap();
+
+        let through_point = space.get_point(&through).unwrap();
+        let offset_after = space.get_point(&parallel_line.point2_id).unwrap().position;
+        assert_ne!(offset_before, offset_after, "offset point should move when its through point is dragged");
+
+        let new_dir = offset_after - through_point.position;
+        let original_dir = Vector2::new(4.0, 2.0);
+        assert!((original_dir.x * new_dir.y - original_dir.y * new_dir.x).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_construct_parallel_stays_parallel_when_reference_line_is_dragged() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(4.0, 2.0, None));
+        let through = space.add_point(Point::new(1.0, 5.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+
+        let parallel = space.construct_parallel(&line, &through).unwrap();
+        let parallel_line = space.get_line(&parallel).unwrap().clone();
+
+        // Reshape the reference line itself, not the through point
+        space.move_point(&p2, 4.0, -8.0).unwrap();
+
+        let through_point = space.get_point(&through).unwrap();
+        let offset_point = space.get_point(&parallel_line.point2_id).unwrap();
+        let new_line_dir = space.get_point(&p2).unwrap().position - space.get_point(&p1).unwrap().position;
+        let offset_dir = offset_point.position - through_point.position;
+        assert!(
+            (new_line_dir.x * offset_dir.y - new_line_dir.y * offset_dir.x).abs() < 1e-9,
+            "offset line should still be parallel to the reshaped reference line"
+        );
+    }
+
+    #[test]
+    fn test_construct_perpendicular_is_orthogonal_to_original() {
+        let mut space = ConstructionSpace::new();
+        let p1 = space.add_point(Point::new(0.0, 0.0, None));
+        let p2 = space.add_point(Point::new(4.0, 2.0, None));
+        let through = space.add_point(Point::new(1.0, 5.0, None));
+        let line = space.construct_line(&p1, &p2, None).unwrap();
+
+        let perpendicular = space.construct_perpendicular(&line, &through).unwrap();
+        let perpendicular_line = space.get_line(&perpendicular).unwrap();
+        let other = space.get_point(&perpendicular_line.point2_id).unwrap();
+        let through_point = space.get_point(&through).unwrap();
+
+        let original_dir = Vector2::new(4.0, 2.0);
+        let new_dir = other.position - through_point.position;
+        assert!(original_dir.dot(&new_dir).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_construct_parallel_rejects_missing_line() {
+        let mut space = ConstructionSpace::new();
+        let through = space.add_point(Point::new(1.0, 5.0, None));
+        assert!(space.construct_parallel("missing", &through).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Dependency-graph recomputation for a construction space
+//!
+//! Lines and circles reference their defining points by ID, so they stay
+//! current for free when a point moves. A constructed `Point`'s position,
+//! though, is a one-time snapshot taken when it was created — dragging a
+//! base point left every intersection point downstream of it stale. This
+//! module topologically sorts a construction's objects by `dependencies`
+//! and replays the recorded `Derivation` of everything downstream of a
+//! moved point, so the whole construction stays live rather than one-shot.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::construction::{translated_direction, ConstructionSpace, Derivation};
+use crate::{GeometryError, Result};
+
+/// Topologically sort every point/line/circle ID in `space` by dependency,
+/// so each ID appears only after everything it depends on. Errors if the
+/// dependency graph contains a cycle — which the public construction API
+/// cannot currently produce, but a live, draggable model should still catch
+/// rather than loop or panic on.
+pub fn topological_order(space: &ConstructionSpace) -> Result<Vec<String>> {
+    let dependencies = dependency_map(space);
+    let mut remaining = dependencies.clone();
+    let mut placed: HashSet<String> = HashSet::new();
+    let mut ordered = Vec::new();
+
+    while !remaining.is_empty() {
+        let ready: Vec<String> = remaining
+            .iter()
+            .filter(|(_, deps)| deps.iter().all(|d| placed.contains(d) || !dependencies.contains_key(d)))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        if ready.is_empty() {
+            return Err(GeometryError::GraphError("dependency cycle detected".to_string()));
+        }
+
+        for id in ready {
+            placed.insert(id.clone());
+            ordered.push(id.clone());
+            remaining.remove(&id);
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Recompute the position of every point downstream of `moved_point_id`, in
+/// dependency order, by replaying its recorded `Derivation`. Called after
+/// the moved point's own position has already been updated.
+pub fn recompute_dependents(space: &mut ConstructionSpace, moved_point_id: &str) -> Result<()> {
+    let order = topological_order(space)?;
+    let downstream = downstream_of(space, moved_point_id);
+
+    for id in order {
+        if !downstream.contains(&id) {
+            continue;
+        }
+        if let Some(derivation) = space.derivation(&id).cloned() {
+            recompute_point(space, &id, &derivation)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn dependency_map(space: &ConstructionSpace) -> HashMap<String, Vec<String>> {
+    let mut dependencies = HashMap::new();
+    for point in space.points() {
+        dependencies.insert(point.id.clone(), point.dependencies.clone());
+    }
+    for line in space.lines() {
+        dependencies.insert(line.id.clone(), line.dependencies.clone());
+    }
+    for circle in space.circles() {
+        dependencies.insert(circle.id.clone(), circle.dependencies.clone());
+    }
+    dependencies
+}
+
+/// Every object ID whose dependencies transitively include `root_id`
+/// Every object (point, line, or circle) that transitively depends on
+/// `root_id`, found by walking `dependency_map` forwards from it. Used both
+/// to recompute dragged points and, by `ConstructionSpace::undo`, to find
+/// everything that must be removed along with a deleted object.
+pub(crate) fn downstream_of(space: &ConstructionSpace, root_id: &str) -> HashSet<String> {
+    let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+    for (id, deps) in dependency_map(space) {
+        for dep in deps {
+            dependents.entry(dep).or_default().push(id.clone());
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(root_id.to_string());
+    while let Some(id) = queue.pop_front() {
+        if let Some(children) = dependents.get(&id) {
+            for child in children {
+                if seen.insert(child.clone()) {
+                    queue.push_back(child.clone());
+                }
+            }
+        }
+    }
+    seen
+}
+
+fn recompute_point(space: &mut ConstructionSpace, point_id: &str, derivation: &Derivation) -> Result<()> {
+    match derivation {
+        Derivation::Intersection { obj1_id, obj2_id, index } => {
+            let intersections = space.compute_intersections(obj1_id, obj2_id)?;
+            let refreshed = intersections.get(*index).ok_or_else(|| GeometryError::InvalidConstruction {
+                reason: format!(
+                    "construction for point {} became degenerate: expected intersection #{} but only {} remain",
+                    point_id,
+                    index,
+                    intersections.len()
+                ),
+            })?;
+            space.set_point_position(point_id, refreshed.position.x, refreshed.position.y)?;
+        }
+        Derivation::OffsetFromLine { line_id, through_point_id, perpendicular } => {
+            let direction = translated_direction(space, line_id, *perpendicular)?;
+            let through = space.get_point(through_point_id).ok_or_else(|| GeometryError::PointNotFound {
+                id: through_point_id.clone(),
+            })?;
+            let (x, y) = (through.position.x + direction.x, through.position.y + direction.y);
+            space.set_point_position(point_id, x, y)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    #[test]
+    fn test_topological_order_respects_dependencies() {
+        let mut space = ConstructionSpace::new();
+        let a = space.add_point(Point::new(0.0, 0.0, None));
+        let b = space.add_point(Point::new(1.0, 0.0, None));
+        let line_id = space.construct_line(&a, &b, None).unwrap();
+
+        let order = topological_order(&space).unwrap();
+        let a_pos = order.iter().position(|id| id == &a).unwrap();
+        let b_pos = order.iter().position(|id| id == &b).unwrap();
+        let line_pos = order.iter().position(|id| id == &line_id).unwrap();
+        assert!(a_pos < line_pos);
+        assert!(b_pos < line_pos);
+    }
+
+    #[test]
+    fn test_move_point_recomputes_intersection() {
+        let mut space = ConstructionSpace::new();
+        let a = space.add_point(Point::new(0.0, 0.0, None));
+        let b = space.add_point(Point::new(10.0, 10.0, None));
+        let c = space.add_point(Point::new(0.0, 10.0, None));
+        let d = space.add_point(Point::new(10.0, 0.0, None));
+        let line1 = space.construct_line(&a, &b, None).unwrap();
+        let line2 = space.construct_line(&c, &d, None).unwrap();
+
+        let intersections = space.find_intersections(&line1, &line2).unwrap();
+        assert_eq!(intersections.len(), 1);
+        let intersection_id = intersections[0].id.clone();
+        assert!(space.get_point(&intersection_id).unwrap().approx_eq(&Point::new(5.0, 5.0, None), 1e-9));
+
+        // Drag b away from the diagonal: line1 now meets line2 at (8, 2) instead of (5, 5)
+        space.move_point(&b, 20.0, 5.0).unwrap();
+
+        let refreshed = space.get_point(&intersection_id).unwrap();
+        assert!(refreshed.approx_eq(&Point::new(8.0, 2.0, None), 1e-9));
+    }
+
+    #[test]
+    fn test_move_point_errors_on_degenerate_intersection() {
+        let mut space = ConstructionSpace::new();
+        let a = space.add_point(Point::new(0.0, 0.0, None));
+        let b = space.add_point(Point::new(10.0, 10.0, None));
+        let c = space.add_point(Point::new(0.0, 10.0, None));
+        let d = space.add_point(Point::new(10.0, 0.0, None));
+        let line1 = space.construct_line(&a, &b, None).unwrap();
+        let line2 = space.construct_line(&c, &d, None).unwrap();
+        space.find_intersections(&line1, &line2).unwrap();
+
+        // Drag line1 until it is parallel to line2 (direction (1, -1))
+        let result = space.move_point(&b, -10.0, 10.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_move_point_rejects_constructed_point() {
+        let mut space = ConstructionSpace::new();
+        let a = space.add_point(Point::new(0.0, 0.0, None));
+        let b = space.add_point(Point::new(10.0, 10.0, None));
+        let c = space.add_point(Point::new(0.0, 10.0, None));
+        let d = space.add_point(Point::new(10.0, 0.0, None));
+        let line1 = space.construct_line(&a, &b, None).unwrap();
+        let line2 = space.construct_line(&c, &d, None).unwrap();
+        let intersections = space.find_intersections(&line1, &line2).unwrap();
+
+        let result = space.move_point(&intersections[0].id, 1.0, 1.0);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Rarity-weighted encounter generation for the Pokédex-style catch loop
+//!
+//! Spawns are drawn from a `WeightedSpawnTable` built with Vose's alias
+//! method, which samples in O(1) after an O(n) one-time setup instead of the
+//! O(log n) of a cumulative-weight binary search.
+
+use crate::collection::{CollectedElement, ElementFactory};
+
+/// A deterministic, seedable PRNG (SplitMix64) so encounters are
+/// reproducible from a seed without pulling in the `rand` crate.
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// A weight table that samples a `CollectedElement` in O(1) via Vose's alias method
+pub struct WeightedSpawnTable {
+    entries: Vec<CollectedElement>,
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedSpawnTable {
+    /// Build a spawn table from candidate elements, weighting rarer entries
+    /// (higher `rarity_score`) lower.
+    pub fn new(entries: Vec<CollectedElement>) -> Self {
+        let weights: Vec<f64> = entries
+            .iter()
+            .map(|e| 1.0 / (e.stats.rarity_score as f64).max(1.0))
+            .collect();
+        let (prob, alias) = build_alias(&weights);
+        Self {
+            entries,
+            prob,
+            alias,
+        }
+    }
+
+    /// Number of candidate entries in the table
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Draw one element, mutating `rng` to advance the stream. `None` if the
+    /// table has no entries to draw from.
+    pub fn sample(&self, rng: &mut SplitMix64) -> Option<&CollectedElement> {
+        let n = self.entries.len();
+        if n == 0 {
+            return None;
+        }
+        let bucket = ((rng.next_f64() * n as f64) as usize).min(n - 1);
+        let u = rng.next_f64();
+
+        if u < self.prob[bucket] {
+            Some(&self.entries[bucket])
+        } else {
+            Some(&self.entries[self.alias[bucket]])
+        }
+    }
+}
+
+/// The standing catalog of elements an encounter can spawn from when the
+/// caller has no more specific candidate list of its own
+pub fn default_candidates() -> Vec<CollectedElement> {
+    vec![
+        ElementFactory::create_point(),
+        ElementFactory::create_line(),
+        ElementFactory::create_circle(),
+        ElementFactory::create_equilateral_triangle(),
+    ]
+}
+
+/// Build the `prob`/`alias` arrays for Vose's alias method
+fn build_alias(weights: &[f64]) -> (Vec<f64>, Vec<usize>) {
+    let n = weights.len();
+    if n == 0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let sum: f64 = weights.iter().sum();
+    let average = sum / n as f64;
+
+    let mut scaled: Vec<f64> = weights.iter().map(|w| w / average).collect();
+    let mut prob = vec![0.0; n];
+    let mut alias = vec![0usize; n];
+
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+    for (i, &p) in scaled.iter().enumerate() {
+        if p < 1.0 {
+            small.push(i);
+        } else {
+            large.push(i);
+        }
+    }
+
+    while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+        prob[s] = scaled[s];
+        alias[s] = l;
+
+        scaled[l] = scaled[l] + scaled[s] - 1.0;
+        if scaled[l] < 1.0 {
+            small.push(l);
+        } else {
+            large.push(l);
+        }
+    }
+
+    for i in small {
+        prob[i] = 1.0;
+    }
+    for i in large {
+        prob[i] = 1.0;
+    }
+
+    (prob, alias)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::ElementFactory;
+
+    #[test]
+    fn test_alias_table_samples_every_entry_eventually() {
+        let entries = vec![
+            ElementFactory::create_point(),
+            ElementFactory::create_circle(),
+            ElementFactory::create_equilateral_triangle(),
+        ];
+        let table = WeightedSpawnTable::new(entries);
+        let mut rng = SplitMix64::new(42);
+
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..500 {
+            seen.insert(table.sample(&mut rng).unwrap().name.clone());
+        }
+
+        assert_eq!(seen.len(), 3);
+    }
+
+    #[test]
+    fn test_sample_on_empty_table_returns_none() {
+        let table = WeightedSpawnTable::new(Vec::new());
+        let mut rng = SplitMix64::new(1);
+        assert!(table.sample(&mut rng).is_none());
+    }
+
+    #[test]
+    fn test_deterministic_for_same_seed() {
+        let entries = vec![ElementFactory::create_point(), ElementFactory::create_circle()];
+        let table = WeightedSpawnTable::new(entries);
+
+        let mut rng1 = SplitMix64::new(7);
+        let mut rng2 = SplitMix64::new(7);
+
+        let sequence1: Vec<String> = (0..20).map(|_| table.sample(&mut rng1).unwrap().name.clone()).collect();
+        let sequence2: Vec<String> = (0..20).map(|_| table.sample(&mut rng2).unwrap().name.clone()).collect();
+
+        assert_eq!(sequence1, sequence2);
+    }
+}
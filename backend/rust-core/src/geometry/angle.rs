@@ -0,0 +1,151 @@
+//! A radians-denominated angle newtype
+//!
+//! `Arc::start_angle`/`end_angle` and the free functions in
+//! `crate::utils::angle_utils` all pass bare `f64` radians, which lets a
+//! caller hand in degrees by mistake or forget to normalize before
+//! comparing two angles. `Angle` wraps a radians value and the existing
+//! `angle_utils` logic behind a type the compiler can check, rather than a
+//! convention callers have to remember. It serializes as a plain number so
+//! existing JSON documents built from raw-`f64` angle fields keep working.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use nalgebra::{Point2, Vector2};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::angle_utils;
+
+/// An angle, stored internally as radians
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Angle(f64);
+
+impl Angle {
+    /// Construct from a value already in radians
+    pub fn radians(radians: f64) -> Self {
+        Self(radians)
+    }
+
+    /// Construct from a value in degrees
+    pub fn degrees(degrees: f64) -> Self {
+        Self(angle_utils::deg_to_rad(degrees))
+    }
+
+    /// This angle's value in radians
+    pub fn as_radians(&self) -> f64 {
+        self.0
+    }
+
+    /// This angle's value in degrees
+    pub fn as_degrees(&self) -> f64 {
+        angle_utils::rad_to_deg(self.0)
+    }
+
+    /// This angle wrapped into `[0, 2π)`
+    pub fn normalized(&self) -> Self {
+        Self(angle_utils::normalize_angle(self.0))
+    }
+
+    /// This angle wrapped into `(-π, π]`
+    pub fn normalized_signed(&self) -> Self {
+        Self(angle_utils::normalize_angle_signed(self.0))
+    }
+
+    /// The angle between two vectors, in `[0, π]`
+    pub fn between_vectors(v1: Vector2<f64>, v2: Vector2<f64>) -> Self {
+        Self(angle_utils::angle_between_vectors(v1, v2))
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, rhs: Angle) -> Angle {
+        Angle(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, rhs: Angle) -> Angle {
+        Angle(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle(-self.0)
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Angle;
+
+    fn mul(self, rhs: f64) -> Angle {
+        Angle(self.0 * rhs)
+    }
+}
+
+/// Extension giving a point its angle from the positive x-axis, as if it
+/// were a vector from the origin, mirroring the SDL-geometry crate's
+/// ergonomic `vec.to_angle()`
+pub trait ToAngle {
+    fn to_angle(&self) -> Angle;
+}
+
+impl ToAngle for Point2<f64> {
+    fn to_angle(&self) -> Angle {
+        Angle(angle_utils::vector_angle(Vector2::new(self.x, self.y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_radians_and_degrees_round_trip() {
+        let angle = Angle::degrees(180.0);
+        assert_abs_diff_eq!(angle.as_radians(), std::f64::consts::PI, epsilon = 1e-10);
+        assert_abs_diff_eq!(Angle::radians(std::f64::consts::PI).as_degrees(), 180.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_normalized_wraps_into_0_to_tau() {
+        let angle = Angle::radians(-std::f64::consts::FRAC_PI_2);
+        assert_abs_diff_eq!(angle.normalized().as_radians(), 1.5 * std::f64::consts::PI, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_normalized_signed_wraps_into_negative_pi_to_pi() {
+        let angle = Angle::radians(1.5 * std::f64::consts::PI);
+        assert_abs_diff_eq!(angle.normalized_signed().as_radians(), -std::f64::consts::FRAC_PI_2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_add_sub_neg_mul_operators() {
+        let a = Angle::radians(1.0);
+        let b = Angle::radians(0.5);
+        assert_abs_diff_eq!((a + b).as_radians(), 1.5, epsilon = 1e-10);
+        assert_abs_diff_eq!((a - b).as_radians(), 0.5, epsilon = 1e-10);
+        assert_abs_diff_eq!((-a).as_radians(), -1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!((a * 2.0).as_radians(), 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_between_vectors_matches_angle_utils() {
+        let v1 = Vector2::new(1.0, 0.0);
+        let v2 = Vector2::new(0.0, 1.0);
+        assert_abs_diff_eq!(Angle::between_vectors(v1, v2).as_radians(), std::f64::consts::FRAC_PI_2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_point_to_angle() {
+        let point = Point2::new(0.0, 1.0);
+        assert_abs_diff_eq!(point.to_angle().as_radians(), std::f64::consts::FRAC_PI_2, epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,239 @@
+//! GeoJSON import/export for the bare geometry primitives
+//!
+//! `crate::serialization` already converts a whole `ConstructionSpace` to and
+//! from a GeoJSON `FeatureCollection`; this module works one level down, on
+//! a single `Polygon`/`Segment`/point collection with no construction-graph
+//! IDs attached, for callers that just want to hand a shape to (or parse one
+//! from) the broader GIS ecosystem. Gated behind the `geojson` feature since
+//! most NERV consumers never touch it.
+//!
+//! Every import path runs coordinates through `is_valid_point` so a GeoJSON
+//! document with a `NaN`/infinite coordinate is rejected rather than quietly
+//! poisoning the construction it's imported into.
+
+use nalgebra::Point2;
+use serde_json::{json, Value};
+
+use super::primitives::{Polygon, Segment};
+use crate::utils::validation::is_valid_point;
+
+impl Polygon {
+    /// Export as a GeoJSON `Polygon` geometry: a single ring, wound
+    /// counter-clockwise per the GeoJSON spec regardless of this polygon's
+    /// own winding, and auto-closed by repeating the first vertex as the last
+    pub fn to_geojson(&self) -> Value {
+        let mut ring = self.vertices.clone();
+        if signed_area(&ring) < 0.0 {
+            ring[1..].reverse();
+        }
+        if let Some(first) = ring.first().copied() {
+            ring.push(first);
+        }
+
+        json!({
+            "type": "Polygon",
+            "coordinates": [ring_to_coords(&ring)]
+        })
+    }
+
+    /// Parse a GeoJSON `Polygon` geometry's outer ring into a `Polygon`,
+    /// dropping the closing vertex GeoJSON repeats. Holes (additional rings)
+    /// are not represented by `Polygon` and are ignored.
+    pub fn from_geojson(v: &Value) -> Result<Self, String> {
+        if v.get("type").and_then(|v| v.as_str()) != Some("Polygon") {
+            return Err("expected a GeoJSON Polygon geometry".to_string());
+        }
+        let rings = v
+            .get("coordinates")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| "Polygon geometry missing \"coordinates\"".to_string())?;
+        let outer = rings
+            .first()
+            .ok_or_else(|| "Polygon geometry has no outer ring".to_string())?;
+
+        let mut vertices = coords_to_points(outer)?;
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Ok(Polygon::new(vertices))
+    }
+}
+
+impl Segment {
+    /// Export as a two-point GeoJSON `LineString` geometry
+    pub fn to_geojson(&self) -> Value {
+        json!({
+            "type": "LineString",
+            "coordinates": ring_to_coords(&[self.start, self.end])
+        })
+    }
+
+    /// Parse a two-point GeoJSON `LineString` geometry into a `Segment`
+    pub fn from_geojson(v: &Value) -> Result<Self, String> {
+        let points = linestring_to_points(v)?;
+        if points.len() != 2 {
+            return Err(format!("expected a 2-point LineString for a Segment, found {}", points.len()));
+        }
+        Ok(Segment::new(points[0], points[1]))
+    }
+}
+
+/// Export an open polyline (an ordered chain of points with no implied
+/// closure) as a GeoJSON `LineString` geometry
+pub fn polyline_to_geojson(points: &[Point2<f64>]) -> Value {
+    json!({
+        "type": "LineString",
+        "coordinates": ring_to_coords(points)
+    })
+}
+
+/// Parse a GeoJSON `LineString` geometry into its chain of points
+pub fn polyline_from_geojson(v: &Value) -> Result<Vec<Point2<f64>>, String> {
+    linestring_to_points(v)
+}
+
+/// Export an unordered collection of points as a GeoJSON `MultiPoint` geometry
+pub fn multipoint_to_geojson(points: &[Point2<f64>]) -> Value {
+    json!({
+        "type": "MultiPoint",
+        "coordinates": ring_to_coords(points)
+    })
+}
+
+/// Parse a GeoJSON `MultiPoint` geometry into its points
+pub fn multipoint_from_geojson(v: &Value) -> Result<Vec<Point2<f64>>, String> {
+    if v.get("type").and_then(|v| v.as_str()) != Some("MultiPoint") {
+        return Err("expected a GeoJSON MultiPoint geometry".to_string());
+    }
+    let coords = v
+        .get("coordinates")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "MultiPoint geometry missing \"coordinates\"".to_string())?;
+    coords_to_points(coords)
+}
+
+fn linestring_to_points(v: &Value) -> Result<Vec<Point2<f64>>, String> {
+    if v.get("type").and_then(|v| v.as_str()) != Some("LineString") {
+        return Err("expected a GeoJSON LineString geometry".to_string());
+    }
+    let coords = v
+        .get("coordinates")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "LineString geometry missing \"coordinates\"".to_string())?;
+    coords_to_points(coords)
+}
+
+fn ring_to_coords(points: &[Point2<f64>]) -> Vec<[f64; 2]> {
+    points.iter().map(|p| [p.x, p.y]).collect()
+}
+
+fn coords_to_points(coords: &[Value]) -> Result<Vec<Point2<f64>>, String> {
+    coords
+        .iter()
+        .map(|c| {
+            let pair = c.as_array().ok_or_else(|| "coordinate is not an array".to_string())?;
+            let x = pair.first().and_then(|v| v.as_f64()).ok_or_else(|| "coordinate missing x".to_string())?;
+            let y = pair.get(1).and_then(|v| v.as_f64()).ok_or_else(|| "coordinate missing y".to_string())?;
+            let point = Point2::new(x, y);
+            if !is_valid_point(&point) {
+                return Err(format!("coordinate [{}, {}] is not finite", x, y));
+            }
+            Ok(point)
+        })
+        .collect()
+}
+
+/// The shoelace signed area of an (unclosed) ring: positive for
+/// counter-clockwise winding, negative for clockwise
+fn signed_area(vertices: &[Point2<f64>]) -> f64 {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| {
+            let j = (i + 1) % n;
+            vertices[i].x * vertices[j].y - vertices[j].x * vertices[i].y
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon_to_geojson_normalizes_winding_and_closes_ring() {
+        // Clockwise square
+        let polygon = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(0.0, 2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.0, 0.0),
+        ]);
+        let geojson = polygon.to_geojson();
+        let ring = geojson["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        assert_eq!(ring[0], json!([0.0, 0.0]));
+        assert_eq!(ring[1], json!([2.0, 0.0]));
+    }
+
+    #[test]
+    fn test_polygon_from_geojson_drops_closing_vertex() {
+        let geojson = json!({
+            "type": "Polygon",
+            "coordinates": [[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [0.0, 0.0]]]
+        });
+        let polygon = Polygon::from_geojson(&geojson).unwrap();
+        assert_eq!(polygon.vertices.len(), 4);
+    }
+
+    #[test]
+    fn test_polygon_from_geojson_rejects_wrong_type() {
+        let geojson = json!({"type": "Point", "coordinates": [0.0, 0.0]});
+        assert!(Polygon::from_geojson(&geojson).is_err());
+    }
+
+    #[test]
+    fn test_polygon_geojson_round_trip() {
+        let polygon = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 3.0),
+        ]);
+        let round_tripped = Polygon::from_geojson(&polygon.to_geojson()).unwrap();
+        assert_eq!(round_tripped.vertices.len(), 3);
+        assert!((round_tripped.area() - polygon.area()).abs() < super::super::primitives::EPSILON);
+    }
+
+    #[test]
+    fn test_segment_geojson_round_trip() {
+        let segment = Segment::new(Point2::new(1.0, 2.0), Point2::new(3.0, 4.0));
+        let round_tripped = Segment::from_geojson(&segment.to_geojson()).unwrap();
+        assert_eq!(round_tripped, segment);
+    }
+
+    #[test]
+    fn test_segment_from_geojson_rejects_wrong_point_count() {
+        let geojson = json!({"type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]});
+        assert!(Segment::from_geojson(&geojson).is_err());
+    }
+
+    #[test]
+    fn test_polyline_geojson_round_trip() {
+        let points = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)];
+        let round_tripped = polyline_from_geojson(&polyline_to_geojson(&points)).unwrap();
+        assert_eq!(round_tripped, points);
+    }
+
+    #[test]
+    fn test_multipoint_geojson_round_trip() {
+        let points = vec![Point2::new(5.0, 5.0), Point2::new(-1.0, 2.0)];
+        let round_tripped = multipoint_from_geojson(&multipoint_to_geojson(&points)).unwrap();
+        assert_eq!(round_tripped, points);
+    }
+
+    #[test]
+    fn test_coords_to_points_rejects_non_finite() {
+        let geojson = json!({"type": "MultiPoint", "coordinates": [[f64::NAN, 0.0]]});
+        assert!(multipoint_from_geojson(&geojson).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+//! Intersection queries between raw geometric primitives
+//!
+//! `geometry::operations` intersects the construction graph's own
+//! `Point`/`Line`/`Circle` objects and always mints a fresh `Point` ready to
+//! be added to a `ConstructionSpace`. This module answers the same question
+//! for the lower-level `Ray`/`Segment`/`Arc`/`Polygon` primitives with no
+//! construction graph involved and a caller-chosen tolerance, which is what
+//! a one-off hit-test or boolean query actually wants.
+
+use nalgebra::{Point2, Vector2};
+
+use super::angle::Angle;
+use super::primitives::{Arc, Polygon, Ray, Segment};
+
+/// The result of intersecting two bounded geometric primitives
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersection {
+    None,
+    Point(Point2<f64>),
+    Two(Point2<f64>, Point2<f64>),
+    Overlap(Segment),
+}
+
+/// Intersect two segments using the standard parametric cross-product form:
+/// solving `p + t*r = q + u*s` for `r = a.end - a.start`, `s = b.end -
+/// b.start`, with `rxs = r × s`. A non-parallel pair (`|rxs| >= tolerance`)
+/// crosses within both segments only if the solved `t` and `u` both fall in
+/// `[0, 1]`; a parallel pair is an intersection only if also collinear, in
+/// which case the overlap (if any) of the two segments is returned.
+pub fn segment_segment(a: &Segment, b: &Segment, tolerance: f64) -> Intersection {
+    let p = a.start;
+    let r = a.end - a.start;
+    let q = b.start;
+    let s = b.end - b.start;
+
+    let rxs = r.x * s.y - r.y * s.x;
+    let qmp = q - p;
+    let qmpxr = qmp.x * r.y - qmp.y * r.x;
+
+    if rxs.abs() < tolerance {
+        if qmpxr.abs() >= tolerance {
+            return Intersection::None;
+        }
+        return segment_collinear_overlap(p, r, q, s, tolerance);
+    }
+
+    let t = (qmp.x * s.y - qmp.y * s.x) / rxs;
+    let u = qmpxr / rxs;
+
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Intersection::Point(p + t * r)
+    } else {
+        Intersection::None
+    }
+}
+
+/// The overlapping sub-segment of two collinear segments `p -> p+r` and `q
+/// -> q+s`, projected onto their shared direction, or `None` if they don't overlap
+fn segment_collinear_overlap(p: Point2<f64>, r: Vector2<f64>, q: Point2<f64>, s: Vector2<f64>, tolerance: f64) -> Intersection {
+    let length = crate::ops::norm(r);
+    if length < tolerance {
+        return Intersection::None;
+    }
+    let dir = r / length;
+    let project = |pt: Point2<f64>| (pt - p).dot(&dir);
+
+    let (lo1, hi1) = (0.0, length);
+    let (a, b) = (project(q), project(q + s));
+    let (lo2, hi2) = if a <= b { (a, b) } else { (b, a) };
+
+    let lo = lo1.max(lo2);
+    let hi = hi1.min(hi2);
+    if lo > hi + tolerance {
+        return Intersection::None;
+    }
+
+    let start = p + lo * dir;
+    let end = p + hi * dir;
+    if crate::ops::distance(start, end) < tolerance {
+        Intersection::Point(start)
+    } else {
+        Intersection::Overlap(Segment::new(start, end))
+    }
+}
+
+/// Intersect a ray with a segment, the same parametric cross-product form
+/// as `segment_segment` but with the ray's own parameter left open-ended
+/// (`t >= 0`) instead of clamped to `[0, 1]`
+pub fn ray_segment(ray: &Ray, segment: &Segment, tolerance: f64) -> Intersection {
+    let p = ray.origin;
+    let r = ray.direction;
+    let q = segment.start;
+    let s = segment.end - segment.start;
+
+    let rxs = r.x * s.y - r.y * s.x;
+    let qmp = q - p;
+    let qmpxr = qmp.x * r.y - qmp.y * r.x;
+
+    if rxs.abs() < tolerance {
+        if qmpxr.abs() >= tolerance {
+            return Intersection::None;
+        }
+        return ray_collinear_overlap(p, r, q, s, tolerance);
+    }
+
+    let t = (qmp.x * s.y - qmp.y * s.x) / rxs;
+    let u = qmpxr / rxs;
+
+    if t >= -tolerance && (0.0..=1.0).contains(&u) {
+        Intersection::Point(p + t.max(0.0) * r)
+    } else {
+        Intersection::None
+    }
+}
+
+/// The overlapping sub-segment of a ray `p -> p + r*t` (`t >= 0`) and a
+/// collinear segment `q -> q+s`, or `None` if they don't overlap
+fn ray_collinear_overlap(p: Point2<f64>, r: Vector2<f64>, q: Point2<f64>, s: Vector2<f64>, tolerance: f64) -> Intersection {
+    // `Ray::direction` is a public field with no guarantee of unit length,
+    // so normalize the same way `segment_collinear_overlap` does - otherwise
+    // `project` returns `t * |r|^2` instead of `t`, and `lo`/`hi` below stop
+    // being valid parameters along `dir`
+    let length = crate::ops::norm(r);
+    if length < tolerance {
+        return Intersection::None;
+    }
+    let dir = r / length;
+    let project = |pt: Point2<f64>| (pt - p).dot(&dir);
+
+    let (a, b) = (project(q), project(q + s));
+    let (lo2, hi2) = if a <= b { (a, b) } else { (b, a) };
+
+    let lo = lo2.max(0.0);
+    let hi = hi2;
+    if lo > hi + tolerance {
+        return Intersection::None;
+    }
+
+    let start = p + lo * dir;
+    let end = p + hi * dir;
+    if crate::ops::distance(start, end) < tolerance {
+        Intersection::Point(start)
+    } else {
+        Intersection::Overlap(Segment::new(start, end))
+    }
+}
+
+/// The (up to two) real roots of `|origin + t*dir - arc.center|^2 =
+/// arc.radius^2`, substituting the line parametrization straight into the
+/// circle equation and solving the resulting quadratic in `t`
+fn line_circle_roots(origin: Point2<f64>, dir: Vector2<f64>, center: Point2<f64>, radius: f64, tolerance: f64) -> Vec<f64> {
+    let oc = origin - center;
+    let a = dir.dot(&dir);
+    if a < tolerance {
+        return Vec::new();
+    }
+    let b = 2.0 * oc.dot(&dir);
+    let c = oc.dot(&oc) - radius * radius;
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return Vec::new();
+    }
+    if discriminant < tolerance {
+        return vec![-b / (2.0 * a)];
+    }
+    let sqrt_d = crate::ops::sqrt(discriminant);
+    vec![(-b - sqrt_d) / (2.0 * a), (-b + sqrt_d) / (2.0 * a)]
+}
+
+/// The points where the line through `origin` with direction `dir` actually
+/// crosses `arc`: roots of `line_circle_roots` that both fall within
+/// `t_bounds` (the owning ray's or segment's own extent) and land on the
+/// swept portion of the circle per `Arc::contains_angle`
+fn arc_crossings(origin: Point2<f64>, dir: Vector2<f64>, arc: &Arc, t_bounds: impl Fn(f64) -> bool, tolerance: f64) -> Vec<Point2<f64>> {
+    let mut hits: Vec<(f64, Point2<f64>)> = line_circle_roots(origin, dir, arc.center, arc.radius, tolerance)
+        .into_iter()
+        .filter(|&t| t_bounds(t))
+        .map(|t| (t, origin + t * dir))
+        .filter(|(_, point)| arc.contains_angle(Angle::radians(crate::ops::atan2(point.y - arc.center.y, point.x - arc.center.x))))
+        .collect();
+    hits.sort_by(|(t1, _), (t2, _)| t1.partial_cmp(t2).unwrap());
+    hits.into_iter().map(|(_, point)| point).collect()
+}
+
+fn crossings_to_intersection(points: Vec<Point2<f64>>) -> Intersection {
+    match points.len() {
+        0 => Intersection::None,
+        1 => Intersection::Point(points[0]),
+        _ => Intersection::Two(points[0], points[1]),
+    }
+}
+
+/// Intersect a ray with an arc
+pub fn ray_arc(ray: &Ray, arc: &Arc, tolerance: f64) -> Intersection {
+    let points = arc_crossings(ray.origin, ray.direction, arc, |t| t >= -tolerance, tolerance);
+    crossings_to_intersection(points)
+}
+
+/// Intersect a segment with an arc
+pub fn segment_arc(segment: &Segment, arc: &Arc, tolerance: f64) -> Intersection {
+    let dir = segment.end - segment.start;
+    let points = arc_crossings(segment.start, dir, arc, |t| (-tolerance..=1.0 + tolerance).contains(&t), tolerance);
+    crossings_to_intersection(points)
+}
+
+/// Intersect a ray with a polygon's boundary, reporting the nearest feature
+/// the ray actually hits (the closest crossing point, or the closest
+/// collinear overlap with an edge)
+pub fn ray_polygon(ray: &Ray, polygon: &Polygon, tolerance: f64) -> Intersection {
+    let n = polygon.vertices.len();
+    if n < 3 {
+        return Intersection::None;
+    }
+
+    let mut nearest: Option<(f64, Intersection)> = None;
+    for i in 0..n {
+        let edge = Segment::new(polygon.vertices[i], polygon.vertices[(i + 1) % n]);
+        let hit = ray_segment(ray, &edge, tolerance);
+
+        let distance = match &hit {
+            Intersection::Point(point) => Some((point - ray.origin).dot(&ray.direction)),
+            Intersection::Overlap(segment) => {
+                let d1 = (segment.start - ray.origin).dot(&ray.direction);
+                let d2 = (segment.end - ray.origin).dot(&ray.direction);
+                Some(d1.min(d2))
+            }
+            Intersection::None | Intersection::Two(..) => None,
+        };
+
+        if let Some(distance) = distance {
+            if nearest.as_ref().map_or(true, |(best, _)| distance < *best) {
+                nearest = Some((distance, hit));
+            }
+        }
+    }
+
+    nearest.map(|(_, hit)| hit).unwrap_or(Intersection::None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_segment_crossing() {
+        let a = Segment::new(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0));
+        let b = Segment::new(Point2::new(1.0, -1.0), Point2::new(1.0, 1.0));
+        match segment_segment(&a, &b, 1e-9) {
+            Intersection::Point(p) => {
+                assert!((p.x - 1.0).abs() < 1e-9);
+                assert!((p.y - 0.0).abs() < 1e-9);
+            }
+            other => panic!("expected a point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_segment_segment_collinear_overlap() {
+        let a = Segment::new(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0));
+        let b = Segment::new(Point2::new(1.0, 0.0), Point2::new(3.0, 0.0));
+        match segment_segment(&a, &b, 1e-9) {
+            Intersection::Overlap(seg) => {
+                let (lo, hi) = (seg.start.x.min(seg.end.x), seg.start.x.max(seg.end.x));
+                assert!((lo - 1.0).abs() < 1e-9);
+                assert!((hi - 2.0).abs() < 1e-9);
+            }
+            other => panic!("expected an overlap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ray_segment_hits_ahead() {
+        let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        let segment = Segment::new(Point2::new(3.0, -1.0), Point2::new(3.0, 1.0));
+        match ray_segment(&ray, &segment, 1e-9) {
+            Intersection::Point(p) => assert!((p.x - 3.0).abs() < 1e-9),
+            other => panic!("expected a point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ray_segment_behind_origin_is_none() {
+        let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        let segment = Segment::new(Point2::new(-3.0, -1.0), Point2::new(-3.0, 1.0));
+        assert_eq!(ray_segment(&ray, &segment, 1e-9), Intersection::None);
+    }
+
+    #[test]
+    fn test_ray_segment_collinear_overlap_with_non_unit_direction() {
+        let mut ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        ray.direction = Vector2::new(3.0, 0.0); // non-unit: direction is a public, mutable field
+        let segment = Segment::new(Point2::new(1.0, 0.0), Point2::new(4.0, 0.0));
+
+        match ray_segment(&ray, &segment, 1e-9) {
+            Intersection::Overlap(seg) => {
+                let (lo, hi) = (seg.start.x.min(seg.end.x), seg.start.x.max(seg.end.x));
+                assert!((lo - 1.0).abs() < 1e-9);
+                assert!((hi - 4.0).abs() < 1e-9);
+            }
+            other => panic!("expected an overlap from (1, 0) to (4, 0), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ray_arc_crosses_circle_twice() {
+        let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::new(1.0, 0.0));
+        let full_circle = Arc::new(Point2::new(0.0, 0.0), 2.0, 0.0, 2.0 * std::f64::consts::PI);
+        match ray_arc(&ray, &full_circle, 1e-9) {
+            Intersection::Two(p1, p2) => {
+                assert!((p1.x + 2.0).abs() < 1e-9);
+                assert!((p2.x - 2.0).abs() < 1e-9);
+            }
+            other => panic!("expected two crossings, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ray_arc_misses_when_angle_excluded() {
+        // Only the right half of the circle is swept, so the ray's left-side
+        // crossing at x = -2 should be discarded
+        let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::new(1.0, 0.0));
+        let right_half = Arc::new(Point2::new(0.0, 0.0), 2.0, -std::f64::consts::FRAC_PI_2, std::f64::consts::FRAC_PI_2);
+        match ray_arc(&ray, &right_half, 1e-9) {
+            Intersection::Point(p) => assert!((p.x - 2.0).abs() < 1e-9),
+            other => panic!("expected a single point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_segment_arc_stops_short_of_the_circle() {
+        let segment = Segment::new(Point2::new(-5.0, 0.0), Point2::new(-3.0, 0.0));
+        let full_circle = Arc::new(Point2::new(0.0, 0.0), 2.0, 0.0, 2.0 * std::f64::consts::PI);
+        assert_eq!(segment_arc(&segment, &full_circle, 1e-9), Intersection::None);
+    }
+
+    #[test]
+    fn test_ray_polygon_hits_nearest_edge() {
+        let square = Polygon::new(vec![
+            Point2::new(1.0, -1.0),
+            Point2::new(3.0, -1.0),
+            Point2::new(3.0, 1.0),
+            Point2::new(1.0, 1.0),
+        ]);
+        let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        match ray_polygon(&ray, &square, 1e-9) {
+            Intersection::Point(p) => assert!((p.x - 1.0).abs() < 1e-9),
+            other => panic!("expected a point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ray_polygon_misses_entirely() {
+        let square = Polygon::new(vec![
+            Point2::new(1.0, 5.0),
+            Point2::new(3.0, 5.0),
+            Point2::new(3.0, 7.0),
+            Point2::new(1.0, 7.0),
+        ]);
+        let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        assert_eq!(ray_polygon(&ray, &square, 1e-9), Intersection::None);
+    }
+}
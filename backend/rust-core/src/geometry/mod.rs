@@ -7,12 +7,21 @@ use nalgebra::{Point2, Vector2};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub mod angle;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+pub mod intersect;
 pub mod primitives;
 pub mod operations;
+pub mod predicates;
+pub mod shape;
 pub mod transformations;
 
+pub use angle::{Angle, ToAngle};
 pub use primitives::*;
 pub use operations::*;
+pub use predicates::{incircle, orient2d};
+pub use shape::Shape;
 pub use transformations::*;
 
 /// A point in 2D Euclidean space
@@ -50,7 +59,7 @@ impl Point {
 
     /// Get the distance to another point
     pub fn distance_to(&self, other: &Point) -> f64 {
-        nalgebra::distance(&self.position, &other.position)
+        crate::ops::distance(self.position, other.position)
     }
 
     /// Check if this point is approximately equal to another
@@ -67,11 +76,20 @@ pub struct Line {
     pub point2_id: String,
     pub label: Option<String>,
     pub dependencies: Vec<String>,
+    /// How far past `point1_id`/`point2_id` this line extends when testing
+    /// intersections: the full infinite line (the default), a ray, or just
+    /// the segment between them
+    pub bounds: Boundedness,
 }
 
 impl Line {
-    /// Create a new line through two points
+    /// Create a new, infinitely-extending line through two points
     pub fn new(point1_id: String, point2_id: String, label: Option<String>) -> Self {
+        Self::new_bounded(point1_id, point2_id, Boundedness::Line, label)
+    }
+
+    /// Create a new line through two points, extending only as far as `bounds` allows
+    pub fn new_bounded(point1_id: String, point2_id: String, bounds: Boundedness, label: Option<String>) -> Self {
         let dependencies = vec![point1_id.clone(), point2_id.clone()];
         Self {
             id: Uuid::new_v4().to_string(),
@@ -79,6 +97,7 @@ impl Line {
             point2_id,
             label,
             dependencies,
+            bounds,
         }
     }
 
@@ -196,6 +215,7 @@ mod tests {
         assert_eq!(line.point2_id, "p2");
         assert_eq!(line.label, Some("AB".to_string()));
         assert_eq!(line.dependencies, vec!["p1", "p2"]);
+        assert_eq!(line.bounds, Boundedness::Line);
     }
 
     #[test]
@@ -2,8 +2,104 @@
 
 use super::{Point, Line, Circle};
 use nalgebra::{Point2, Vector2};
+use serde::{Deserialize, Serialize};
 use crate::{GeometryError, Result};
 
+/// How far along its defining points a `Line` should be treated as extending
+/// when testing an intersection candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Boundedness {
+    /// Extends infinitely in both directions through `p1` and `p2`
+    Line,
+    /// Extends infinitely past `p2`, but stops at `p1`
+    Ray,
+    /// Stops at both `p1` and `p2`
+    Segment,
+}
+
+impl Boundedness {
+    /// Whether a parametric position `t` along `p1 -> p2` falls within these bounds
+    fn contains(&self, t: f64, tolerance: f64) -> bool {
+        match self {
+            Boundedness::Line => true,
+            Boundedness::Ray => t >= -tolerance,
+            Boundedness::Segment => t >= -tolerance && t <= 1.0 + tolerance,
+        }
+    }
+}
+
+/// The result of intersecting two bounded segments: a single crossing
+/// point, the overlapping sub-segment of two collinear segments, or no
+/// intersection at all
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersection {
+    Point(Point2<f64>),
+    Overlap(Point2<f64>, Point2<f64>),
+    None,
+}
+
+/// Intersect the bounded segments `p1 -> p2` and `p3 -> p4` using the
+/// standard parametric form: with `s1 = p2 - p1`, `s2 = p4 - p3`, and
+/// `denom = -s2.x*s1.y + s1.x*s2.y`, a non-parallel pair crosses at
+/// `s1*t + p1` when both `s = (-s1.y*(p1.x-p3.x) + s1.x*(p1.y-p3.y))/denom`
+/// and `t = (s2.x*(p1.y-p3.y) - s2.y*(p1.x-p3.x))/denom` fall in `[0, 1]`.
+/// A parallel pair (`denom ≈ 0`) is only an intersection if also collinear,
+/// in which case the overlap (if any) of the two segments is returned.
+pub fn segment_segment_intersection(p1: Point2<f64>, p2: Point2<f64>, p3: Point2<f64>, p4: Point2<f64>) -> Intersection {
+    let s1 = p2 - p1;
+    let s2 = p4 - p3;
+    let denom = -s2.x * s1.y + s1.x * s2.y;
+
+    if denom.abs() < 1e-10 {
+        // Parallel: an intersection is only possible if also collinear
+        let cross = (p3.x - p1.x) * s1.y - (p3.y - p1.y) * s1.x;
+        if cross.abs() >= 1e-10 {
+            return Intersection::None;
+        }
+        return collinear_overlap(p1, p2, p3, p4);
+    }
+
+    let s = (-s1.y * (p1.x - p3.x) + s1.x * (p1.y - p3.y)) / denom;
+    let t = (s2.x * (p1.y - p3.y) - s2.y * (p1.x - p3.x)) / denom;
+
+    if (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t) {
+        Intersection::Point(p1 + t * s1)
+    } else {
+        Intersection::None
+    }
+}
+
+/// The overlapping sub-segment of two collinear segments, projected onto
+/// their shared direction and expressed back in 2D, or `None` if they don't
+/// overlap at all
+fn collinear_overlap(p1: Point2<f64>, p2: Point2<f64>, p3: Point2<f64>, p4: Point2<f64>) -> Intersection {
+    let dir = (p2 - p1).normalize();
+    let project = |p: Point2<f64>| (p - p1).dot(&dir);
+
+    let (lo1, hi1) = {
+        let (a, b) = (0.0, project(p2));
+        if a <= b { (a, b) } else { (b, a) }
+    };
+    let (lo2, hi2) = {
+        let (a, b) = (project(p3), project(p4));
+        if a <= b { (a, b) } else { (b, a) }
+    };
+
+    let lo = lo1.max(lo2);
+    let hi = hi1.min(hi2);
+    if lo > hi + 1e-10 {
+        return Intersection::None;
+    }
+
+    let start = p1 + lo * dir;
+    let end = p1 + hi * dir;
+    if (end - start).norm() < 1e-10 {
+        Intersection::Point(start)
+    } else {
+        Intersection::Overlap(start, end)
+    }
+}
+
 /// Calculate intersections between two lines
 pub fn line_line_intersection(
     line1: &Line,
@@ -15,25 +111,32 @@ pub fn line_line_intersection(
 ) -> Result<Vec<Point>> {
     let dir1 = p1b.position - p1a.position;
     let dir2 = p2b.position - p2a.position;
-    
-    // Check if lines are parallel
-    let determinant = dir1.x * dir2.y - dir1.y * dir2.x;
-    if determinant.abs() < 1e-10 {
+
+    // Lines are parallel iff dir1 and dir2 are: that's the same turn
+    // orient2d(p1a, p1b, p1a + dir2) reports for the virtual third point.
+    // This exact predicate is also the determinant the intersection formula
+    // below divides by, so reuse its value instead of recomputing it with
+    // plain floats - otherwise cancellation can make the two disagree about
+    // whether the lines are parallel, dividing by an exact zero and
+    // producing a non-finite point.
+    let virtual_c = Point2::new(p1a.position.x + dir2.x, p1a.position.y + dir2.y);
+    let determinant = super::predicates::orient2d(p1a.position, p1b.position, virtual_c);
+    if determinant == 0.0 {
         return Ok(Vec::new()); // Parallel lines (no intersection or infinite intersections)
     }
-    
+
     let diff = p2a.position - p1a.position;
     let t = (diff.x * dir2.y - diff.y * dir2.x) / determinant;
-    
+
     let intersection_point = p1a.position + t * dir1;
-    
+
     let point = Point::constructed(
         intersection_point.x,
         intersection_point.y,
         None,
         vec![line1.id.clone(), line2.id.clone()],
     );
-    
+
     Ok(vec![point])
 }
 
@@ -47,13 +150,13 @@ pub fn line_circle_intersection(
     radius_point: &Point,
 ) -> Result<Vec<Point>> {
     let radius = center.distance_to(radius_point);
-    let dir = (p2.position - p1.position).normalize();
+    let dir = crate::ops::normalize(p2.position - p1.position);
     let to_center = center.position - p1.position;
     
     // Project center onto line
     let projection_length = to_center.dot(&dir);
     let closest_point = p1.position + projection_length * dir;
-    let distance_to_line = nalgebra::distance(&center.position, &closest_point);
+    let distance_to_line = crate::ops::distance(center.position, closest_point);
     
     if distance_to_line > radius + 1e-10 {
         return Ok(Vec::new()); // No intersection
@@ -72,7 +175,7 @@ pub fn line_circle_intersection(
         intersections.push(point);
     } else if distance_to_line < radius {
         // Two intersections
-        let chord_half_length = (radius * radius - distance_to_line * distance_to_line).sqrt();
+        let chord_half_length = crate::ops::sqrt(radius * radius - distance_to_line * distance_to_line);
         
         let intersection1 = closest_point + chord_half_length * dir;
         let intersection2 = closest_point - chord_half_length * dir;
@@ -98,6 +201,102 @@ pub fn line_circle_intersection(
     Ok(intersections)
 }
 
+/// Like `line_line_intersection`, but discards candidates that fall outside
+/// each line's bounds (a drawn segment or ray rather than the full line)
+pub fn line_line_intersection_bounded(
+    line1: &Line,
+    p1a: &Point,
+    p1b: &Point,
+    bounds1: Boundedness,
+    line2: &Line,
+    p2a: &Point,
+    p2b: &Point,
+    bounds2: Boundedness,
+) -> Result<Vec<Point>> {
+    let dir1 = p1b.position - p1a.position;
+    let dir2 = p2b.position - p2a.position;
+
+    let determinant = dir1.x * dir2.y - dir1.y * dir2.x;
+
+    let virtual_c = Point2::new(p1a.position.x + dir2.x, p1a.position.y + dir2.y);
+    if super::predicates::orient2d(p1a.position, p1b.position, virtual_c) == 0.0 {
+        return Ok(Vec::new()); // Parallel lines (no intersection or infinite intersections)
+    }
+
+    let diff = p2a.position - p1a.position;
+    let t = (diff.x * dir2.y - diff.y * dir2.x) / determinant;
+    let s = (diff.x * dir1.y - diff.y * dir1.x) / determinant;
+
+    if !bounds1.contains(t, 1e-10) || !bounds2.contains(s, 1e-10) {
+        return Ok(Vec::new());
+    }
+
+    let intersection_point = p1a.position + t * dir1;
+
+    let point = Point::constructed(
+        intersection_point.x,
+        intersection_point.y,
+        None,
+        vec![line1.id.clone(), line2.id.clone()],
+    );
+
+    Ok(vec![point])
+}
+
+/// Like `line_circle_intersection`, but discards chord endpoints that fall
+/// outside the line's bounds (a drawn segment or ray rather than the full line)
+pub fn line_circle_intersection_bounded(
+    line: &Line,
+    p1: &Point,
+    p2: &Point,
+    bounds: Boundedness,
+    circle: &Circle,
+    center: &Point,
+    radius_point: &Point,
+) -> Result<Vec<Point>> {
+    let radius = center.distance_to(radius_point);
+    let full_dir = p2.position - p1.position;
+    let length = crate::ops::norm(full_dir);
+    if length < 1e-10 {
+        return Ok(Vec::new());
+    }
+    let dir = full_dir / length;
+    let to_center = center.position - p1.position;
+
+    let projection_length = to_center.dot(&dir);
+    let closest_point = p1.position + projection_length * dir;
+    let distance_to_line = crate::ops::distance(center.position, closest_point);
+
+    if distance_to_line > radius + 1e-10 {
+        return Ok(Vec::new()); // No intersection
+    }
+
+    let mut intersections = Vec::new();
+    let mut push_if_bounded = |position: Point2<f64>, intersections: &mut Vec<Point>| {
+        let t = (position - p1.position).dot(&dir) / length;
+        if bounds.contains(t, 1e-10) {
+            intersections.push(Point::constructed(
+                position.x,
+                position.y,
+                None,
+                vec![line.id.clone(), circle.id.clone()],
+            ));
+        }
+    };
+
+    if (distance_to_line - radius).abs() < 1e-10 {
+        // Tangent - one intersection
+        push_if_bounded(closest_point, &mut intersections);
+    } else if distance_to_line < radius {
+        // Two intersections
+        let chord_half_length = crate::ops::sqrt(radius * radius - distance_to_line * distance_to_line);
+        push_if_bounded(closest_point + chord_half_length * dir, &mut intersections);
+        push_if_bounded(closest_point - chord_half_length * dir, &mut intersections);
+    }
+
+    Ok(intersections)
+}
+
 /// Calculate intersections between two circles
 pub fn circle_circle_intersection(
     circle1: &Circle,
@@ -126,7 +325,7 @@ pub fn circle_circle_intersection(
     
     // Calculate intersection points
     let a = (r1 * r1 - r2 * r2 + d * d) / (2.0 * d);
-    let h = (r1 * r1 - a * a).sqrt();
+    let h = crate::ops::sqrt(r1 * r1 - a * a);
     
     // Point on line between centers
     let direction = (center2.position - center1.position) / d;
@@ -169,11 +368,10 @@ pub fn circle_circle_intersection(
     Ok(intersections)
 }
 
-/// Check if three points are collinear
-pub fn are_collinear(p1: &Point, p2: &Point, p3: &Point, tolerance: f64) -> bool {
-    let area = 0.5 * ((p2.position.x - p1.position.x) * (p3.position.y - p1.position.y)
-                    - (p3.position.x - p1.position.x) * (p2.position.y - p1.position.y));
-    area.abs() < tolerance
+/// Check if three points are collinear, using the exact `orient2d`
+/// predicate rather than a fixed-tolerance area test
+pub fn are_collinear(p1: &Point, p2: &Point, p3: &Point) -> bool {
+    super::predicates::orient2d(p1.position, p2.position, p3.position) == 0.0
 }
 
 /// Calculate the perpendicular bisector of two points
@@ -184,24 +382,24 @@ pub fn perpendicular_bisector(p1: &Point, p2: &Point) -> Result<(Point2<f64>, Ve
     );
     
     let direction = p2.position - p1.position;
-    let perpendicular = Vector2::new(-direction.y, direction.x).normalize();
+    let perpendicular = crate::ops::normalize(Vector2::new(-direction.y, direction.x));
     
     Ok((midpoint, perpendicular))
 }
 
 /// Calculate the angle bisector of three points
 pub fn angle_bisector(p1: &Point, vertex: &Point, p2: &Point) -> Result<Vector2<f64>> {
-    let v1 = (p1.position - vertex.position).normalize();
-    let v2 = (p2.position - vertex.position).normalize();
+    let v1 = crate::ops::normalize(p1.position - vertex.position);
+    let v2 = crate::ops::normalize(p2.position - vertex.position);
     
-    let bisector = (v1 + v2).normalize();
+    let bisector = crate::ops::normalize(v1 + v2);
     Ok(bisector)
 }
 
 /// Calculate the circumcenter of three points (center of circumscribed circle)
 pub fn circumcenter(p1: &Point, p2: &Point, p3: &Point) -> Result<Point> {
     // Check if points are collinear
-    if are_collinear(p1, p2, p3, 1e-10) {
+    if are_collinear(p1, p2, p3) {
         return Err(GeometryError::InvalidConstruction {
             reason: "Cannot find circumcenter of collinear points".to_string(),
         });
@@ -262,15 +460,166 @@ mod tests {
         assert_abs_diff_eq!(center.position.y, 1.5, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_segment_intersection_within_bounds() {
+        let p1 = Point::new(0.0, 0.0, None);
+        let p2 = Point::new(2.0, 0.0, None);
+        let p3 = Point::new(1.0, -1.0, None);
+        let p4 = Point::new(1.0, 1.0, None);
+
+        let line1 = Line::new(p1.id.clone(), p2.id.clone(), None);
+        let line2 = Line::new(p3.id.clone(), p4.id.clone(), None);
+
+        let intersections = line_line_intersection_bounded(
+            &line1, &p1, &p2, Boundedness::Segment, &line2, &p3, &p4, Boundedness::Segment,
+        )
+        .unwrap();
+
+        assert_eq!(intersections.len(), 1);
+    }
+
+    #[test]
+    fn test_segment_intersection_outside_bounds_is_dropped() {
+        // The infinite lines cross at (1, 0), but the first segment ends at (0.5, 0)
+        let p1 = Point::new(0.0, 0.0, None);
+        let p2 = Point::new(0.5, 0.0, None);
+        let p3 = Point::new(1.0, -1.0, None);
+        let p4 = Point::new(1.0, 1.0, None);
+
+        let line1 = Line::new(p1.id.clone(), p2.id.clone(), None);
+        let line2 = Line::new(p3.id.clone(), p4.id.clone(), None);
+
+        let intersections = line_line_intersection_bounded(
+            &line1, &p1, &p2, Boundedness::Segment, &line2, &p3, &p4, Boundedness::Segment,
+        )
+        .unwrap();
+
+        assert!(intersections.is_empty());
+    }
+
+    #[test]
+    fn test_ray_intersection_behind_origin_is_dropped() {
+        // Line2 crosses line1's backing line at (-1, 0), behind p1 on the ray p1->p2
+        let p1 = Point::new(0.0, 0.0, None);
+        let p2 = Point::new(1.0, 0.0, None);
+        let p3 = Point::new(-1.0, -1.0, None);
+        let p4 = Point::new(-1.0, 1.0, None);
+
+        let line1 = Line::new(p1.id.clone(), p2.id.clone(), None);
+        let line2 = Line::new(p3.id.clone(), p4.id.clone(), None);
+
+        let intersections = line_line_intersection_bounded(
+            &line1, &p1, &p2, Boundedness::Ray, &line2, &p3, &p4, Boundedness::Line,
+        )
+        .unwrap();
+
+        assert!(intersections.is_empty());
+    }
+
+    #[test]
+    fn test_segment_circle_intersection_drops_out_of_bounds_chord_point() {
+        // Circle centered at origin, radius 5; the segment from (4, -10) to (4, -6)
+        // lies on the chord's line but entirely below the actual intersection points
+        let center = Point::new(0.0, 0.0, None);
+        let radius_point = Point::new(5.0, 0.0, None);
+        let circle = Circle::new(center.id.clone(), radius_point.id.clone(), None);
+
+        let p1 = Point::new(4.0, -10.0, None);
+        let p2 = Point::new(4.0, -6.0, None);
+        let line = Line::new(p1.id.clone(), p2.id.clone(), None);
+
+        let intersections = line_circle_intersection_bounded(
+            &line,
+            &p1,
+            &p2,
+            Boundedness::Segment,
+            &circle,
+            &center,
+            &radius_point,
+        )
+        .unwrap();
+
+        assert!(intersections.is_empty());
+    }
+
     #[test]
     fn test_collinearity() {
         let p1 = Point::new(0.0, 0.0, None);
         let p2 = Point::new(1.0, 1.0, None);
         let p3 = Point::new(2.0, 2.0, None);
         
-        assert!(are_collinear(&p1, &p2, &p3, 1e-10));
+        assert!(are_collinear(&p1, &p2, &p3));
         
         let p4 = Point::new(2.0, 1.0, None);
-        assert!(!are_collinear(&p1, &p2, &p4, 1e-10));
+        assert!(!are_collinear(&p1, &p2, &p4));
+    }
+
+    #[test]
+    fn test_segment_segment_intersection_crossing_point() {
+        let p1 = Point2::new(0.0, 0.0);
+        let p2 = Point2::new(2.0, 0.0);
+        let p3 = Point2::new(1.0, -1.0);
+        let p4 = Point2::new(1.0, 1.0);
+
+        match segment_segment_intersection(p1, p2, p3, p4) {
+            Intersection::Point(p) => {
+                assert!((p.x - 1.0).abs() < 1e-10);
+                assert!((p.y - 0.0).abs() < 1e-10);
+            }
+            other => panic!("expected a crossing point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_segment_segment_intersection_parallel_non_collinear_is_none() {
+        let p1 = Point2::new(0.0, 0.0);
+        let p2 = Point2::new(1.0, 0.0);
+        let p3 = Point2::new(0.0, 1.0);
+        let p4 = Point2::new(1.0, 1.0);
+
+        assert_eq!(segment_segment_intersection(p1, p2, p3, p4), Intersection::None);
+    }
+
+    #[test]
+    fn test_segment_segment_intersection_collinear_disjoint_is_none() {
+        let p1 = Point2::new(0.0, 0.0);
+        let p2 = Point2::new(1.0, 0.0);
+        let p3 = Point2::new(2.0, 0.0);
+        let p4 = Point2::new(3.0, 0.0);
+
+        assert_eq!(segment_segment_intersection(p1, p2, p3, p4), Intersection::None);
+    }
+
+    #[test]
+    fn test_segment_segment_intersection_collinear_overlap() {
+        let p1 = Point2::new(0.0, 0.0);
+        let p2 = Point2::new(2.0, 0.0);
+        let p3 = Point2::new(1.0, 0.0);
+        let p4 = Point2::new(3.0, 0.0);
+
+        match segment_segment_intersection(p1, p2, p3, p4) {
+            Intersection::Overlap(a, b) => {
+                let (lo, hi) = (a.x.min(b.x), a.x.max(b.x));
+                assert!((lo - 1.0).abs() < 1e-10);
+                assert!((hi - 2.0).abs() < 1e-10);
+            }
+            other => panic!("expected an overlap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_segment_segment_intersection_collinear_touching_at_a_point() {
+        let p1 = Point2::new(0.0, 0.0);
+        let p2 = Point2::new(1.0, 0.0);
+        let p3 = Point2::new(1.0, 0.0);
+        let p4 = Point2::new(2.0, 0.0);
+
+        match segment_segment_intersection(p1, p2, p3, p4) {
+            Intersection::Point(p) => {
+                assert!((p.x - 1.0).abs() < 1e-10);
+                assert!((p.y - 0.0).abs() < 1e-10);
+            }
+            other => panic!("expected a touching point, got {:?}", other),
+        }
     }
 }
\ No newline at end of file
@@ -2,6 +2,10 @@
 
 use nalgebra::{Point2, Vector2};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::angle::Angle;
 
 /// Tolerance for floating point comparisons
 pub const EPSILON: f64 = 1e-10;
@@ -18,7 +22,7 @@ impl Ray {
     pub fn new(origin: Point2<f64>, direction: Vector2<f64>) -> Self {
         Self {
             origin,
-            direction: direction.normalize(),
+            direction: crate::ops::normalize(direction),
         }
     }
 
@@ -43,7 +47,7 @@ impl Segment {
 
     /// Get the length of the segment
     pub fn length(&self) -> f64 {
-        nalgebra::distance(&self.start, &self.end)
+        crate::ops::distance(self.start, self.end)
     }
 
     /// Get the midpoint of the segment
@@ -56,15 +60,17 @@ impl Segment {
 
     /// Get the direction vector of the segment
     pub fn direction(&self) -> Vector2<f64> {
-        (self.end - self.start).normalize()
+        crate::ops::normalize(self.end - self.start)
     }
 
     /// Check if a point lies on this segment
     pub fn contains_point(&self, point: &Point2<f64>, tolerance: f64) -> bool {
+        use crate::ops::FloatPow;
+
         // Check if point is collinear with segment
         let cross_product = (point.y - self.start.y) * (self.end.x - self.start.x)
                           - (point.x - self.start.x) * (self.end.y - self.start.y);
-        
+
         if cross_product.abs() > tolerance {
             return false;
         }
@@ -72,9 +78,9 @@ impl Segment {
         // Check if point is within segment bounds
         let dot_product = (point.x - self.start.x) * (self.end.x - self.start.x)
                         + (point.y - self.start.y) * (self.end.y - self.start.y);
-        
-        let squared_length = (self.end.x - self.start.x).powi(2) + (self.end.y - self.start.y).powi(2);
-        
+
+        let squared_length = (self.end.x - self.start.x).squared() + (self.end.y - self.start.y).squared();
+
         dot_product >= -tolerance && dot_product <= squared_length + tolerance
     }
 }
@@ -84,34 +90,42 @@ impl Segment {
 pub struct Arc {
     pub center: Point2<f64>,
     pub radius: f64,
-    pub start_angle: f64,
-    pub end_angle: f64,
+    pub start_angle: Angle,
+    pub end_angle: Angle,
 }
 
 impl Arc {
-    /// Create a new arc
+    /// Create a new arc. `start_angle`/`end_angle` are radians, matching
+    /// `Angle`'s own serialized form
     pub fn new(center: Point2<f64>, radius: f64, start_angle: f64, end_angle: f64) -> Self {
         Self {
             center,
             radius,
-            start_angle,
-            end_angle,
+            start_angle: Angle::radians(start_angle),
+            end_angle: Angle::radians(end_angle),
         }
     }
 
     /// Get a point on the arc at the given angle
-    pub fn point_at_angle(&self, angle: f64) -> Point2<f64> {
+    pub fn point_at_angle(&self, angle: Angle) -> Point2<f64> {
+        let radians = angle.as_radians();
         Point2::new(
-            self.center.x + self.radius * angle.cos(),
-            self.center.y + self.radius * angle.sin(),
+            self.center.x + self.radius * crate::ops::cos(radians),
+            self.center.y + self.radius * crate::ops::sin(radians),
         )
     }
 
     /// Check if an angle is within this arc
-    pub fn contains_angle(&self, angle: f64) -> bool {
-        let normalized_start = self.start_angle % (2.0 * std::f64::consts::PI);
-        let normalized_end = self.end_angle % (2.0 * std::f64::consts::PI);
-        let normalized_angle = angle % (2.0 * std::f64::consts::PI);
+    pub fn contains_angle(&self, angle: Angle) -> bool {
+        // A sweep of a full turn or more covers every angle, even though its
+        // normalized start and end coincide
+        if (self.end_angle.as_radians() - self.start_angle.as_radians()).abs() >= std::f64::consts::TAU - EPSILON {
+            return true;
+        }
+
+        let normalized_start = self.start_angle.normalized().as_radians();
+        let normalized_end = self.end_angle.normalized().as_radians();
+        let normalized_angle = angle.normalized().as_radians();
 
         if normalized_start <= normalized_end {
             normalized_angle >= normalized_start && normalized_angle <= normalized_end
@@ -122,7 +136,7 @@ impl Arc {
 
     /// Get the arc length
     pub fn length(&self) -> f64 {
-        let angle_diff = (self.end_angle - self.start_angle).abs();
+        let angle_diff = (self.end_angle.as_radians() - self.start_angle.as_radians()).abs();
         self.radius * angle_diff
     }
 }
@@ -210,6 +224,242 @@ impl Polygon {
 
         centroid
     }
+
+    /// Decompose this polygon into triangles by ear clipping, handling
+    /// concave (but simple, non-self-intersecting) polygons like the L-shape
+    /// in the tests below. Repeatedly finds a convex vertex whose triangle
+    /// with its neighbors contains no other remaining vertex - an "ear" - clips
+    /// it off, and continues until three vertices remain. Returns an empty
+    /// `Vec` for degenerate input (fewer than 3 vertices, or zero area).
+    pub fn triangulate(&self) -> Vec<Triangle> {
+        let n = self.vertices.len();
+        if n < 3 || self.area() < EPSILON {
+            return Vec::new();
+        }
+
+        // The signed area's sign gives the polygon's winding, so "convex"
+        // can be judged by matching cross-product sign rather than assuming CCW
+        let signed_area: f64 = (0..n)
+            .map(|i| {
+                let j = (i + 1) % n;
+                self.vertices[i].x * self.vertices[j].y - self.vertices[j].x * self.vertices[i].y
+            })
+            .sum();
+        let ccw = signed_area > 0.0;
+
+        let mut remaining: Vec<usize> = (0..n).collect();
+        let mut triangles = Vec::with_capacity(n - 2);
+
+        while remaining.len() > 3 {
+            let ear_index = (0..remaining.len())
+                .find(|&i| self.is_ear(&remaining, i, ccw))
+                .unwrap_or(0); // no valid ear found (shouldn't happen for a simple polygon): clip anyway rather than loop forever
+
+            let m = remaining.len();
+            let prev = remaining[(ear_index + m - 1) % m];
+            let cur = remaining[ear_index];
+            let next = remaining[(ear_index + 1) % m];
+            triangles.push(Triangle::new(self.vertices[prev], self.vertices[cur], self.vertices[next]));
+            remaining.remove(ear_index);
+        }
+
+        triangles.push(Triangle::new(self.vertices[remaining[0]], self.vertices[remaining[1]], self.vertices[remaining[2]]));
+        triangles
+    }
+
+    /// Whether the vertex at `remaining[index]` is currently an ear: its
+    /// triangle with its neighbors turns the same way as the polygon's own
+    /// winding, and no other remaining vertex lies inside that triangle or on
+    /// its edges
+    fn is_ear(&self, remaining: &[usize], index: usize, ccw: bool) -> bool {
+        let m = remaining.len();
+        let prev = self.vertices[remaining[(index + m - 1) % m]];
+        let cur = self.vertices[remaining[index]];
+        let next = self.vertices[remaining[(index + 1) % m]];
+
+        let cross = (cur.x - prev.x) * (next.y - cur.y) - (cur.y - prev.y) * (next.x - cur.x);
+        let is_convex_vertex = if ccw { cross > EPSILON } else { cross < -EPSILON };
+        if !is_convex_vertex {
+            return false;
+        }
+
+        (0..m).all(|i| {
+            if i == index || i == (index + m - 1) % m || i == (index + 1) % m {
+                return true;
+            }
+            !point_in_triangle(self.vertices[remaining[i]], prev, cur, next)
+        })
+    }
+
+    /// The polygon's pole of inaccessibility: the point deepest inside it,
+    /// found by Mapbox's polylabel quadtree search. Unlike `centroid()`,
+    /// which can fall outside a concave shape (the L-shape below, for
+    /// instance), this is guaranteed to land inside - useful for placing a
+    /// label. Search stops once no remaining cell could possibly beat the
+    /// best point found by more than `precision`.
+    pub fn label_point(&self, precision: f64) -> Point2<f64> {
+        if self.vertices.len() < 3 {
+            return self.centroid();
+        }
+
+        let mut min = self.vertices[0];
+        let mut max = self.vertices[0];
+        for &v in &self.vertices[1..] {
+            min.x = min.x.min(v.x);
+            min.y = min.y.min(v.y);
+            max.x = max.x.max(v.x);
+            max.y = max.y.max(v.y);
+        }
+        let width = max.x - min.x;
+        let height = max.y - min.y;
+        if width < EPSILON || height < EPSILON {
+            return self.centroid();
+        }
+
+        let cell_size = width.min(height);
+        let half = cell_size / 2.0;
+
+        let mut heap = BinaryHeap::new();
+        let mut x = min.x;
+        while x < max.x {
+            let mut y = min.y;
+            while y < max.y {
+                heap.push(LabelCell::new(Point2::new(x + half, y + half), half, self));
+                y += cell_size;
+            }
+            x += cell_size;
+        }
+
+        // The centroid is a reasonable baseline even when it falls outside
+        // the polygon (its signed distance will just be negative, so any
+        // interior cell immediately displaces it)
+        let mut best = LabelCell::new(self.centroid(), 0.0, self);
+
+        while let Some(cell) = heap.pop() {
+            if cell.distance > best.distance {
+                best = cell.clone();
+            }
+            if cell.max_distance - best.distance <= precision {
+                continue;
+            }
+
+            let quarter = cell.half / 2.0;
+            for (dx, dy) in [(-quarter, -quarter), (quarter, -quarter), (-quarter, quarter), (quarter, quarter)] {
+                heap.push(LabelCell::new(Point2::new(cell.center.x + dx, cell.center.y + dy), quarter, self));
+            }
+        }
+
+        best.center
+    }
+
+    /// The signed distance from `point` to the polygon's boundary: positive
+    /// inside (the minimum distance to any edge), negative outside
+    fn signed_distance(&self, point: Point2<f64>) -> f64 {
+        let n = self.vertices.len();
+        let mut min_distance = f64::INFINITY;
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            min_distance = min_distance.min(point_segment_distance(point, a, b));
+        }
+
+        if self.contains_via_ray_cast(point) {
+            min_distance
+        } else {
+            -min_distance
+        }
+    }
+
+    /// Point-in-polygon test by ray casting: count how many edges a
+    /// horizontal ray from `point` crosses, odd means inside
+    fn contains_via_ray_cast(&self, point: Point2<f64>) -> bool {
+        let n = self.vertices.len();
+        let mut inside = false;
+        let mut j = n - 1;
+        for i in 0..n {
+            let vi = self.vertices[i];
+            let vj = self.vertices[j];
+            if (vi.y > point.y) != (vj.y > point.y)
+                && point.x < (vj.x - vi.x) * (point.y - vi.y) / (vj.y - vi.y) + vi.x
+            {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
+/// The minimum distance from `p` to the segment `a`-`b`
+fn point_segment_distance(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.x * ab.x + ab.y * ab.y;
+    if len_sq < EPSILON {
+        return crate::ops::distance(p, a);
+    }
+
+    let t = ((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / len_sq;
+    let t = t.clamp(0.0, 1.0);
+    let closest = Point2::new(a.x + t * ab.x, a.y + t * ab.y);
+    crate::ops::distance(p, closest)
+}
+
+/// A candidate square cell in the `label_point` quadtree search, ordered in
+/// the max-heap by `max_distance` - the best this cell's center-to-boundary
+/// distance could possibly reach if subdivided indefinitely
+#[derive(Clone)]
+struct LabelCell {
+    center: Point2<f64>,
+    half: f64,
+    distance: f64,
+    max_distance: f64,
+}
+
+impl LabelCell {
+    fn new(center: Point2<f64>, half: f64, polygon: &Polygon) -> Self {
+        let distance = polygon.signed_distance(center);
+        Self {
+            center,
+            half,
+            distance,
+            max_distance: distance + half * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for LabelCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance == other.max_distance
+    }
+}
+
+impl Eq for LabelCell {}
+
+impl PartialOrd for LabelCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LabelCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance.partial_cmp(&other.max_distance).unwrap()
+    }
+}
+
+/// Whether `p` lies inside triangle `a`-`b`-`c` or on its boundary, via barycentric
+/// sign-of-cross-product containment with `EPSILON` tolerance
+fn point_in_triangle(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> bool {
+    let cross = |o: Point2<f64>, u: Point2<f64>, v: Point2<f64>| (u.x - o.x) * (v.y - o.y) - (u.y - o.y) * (v.x - o.x);
+
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+
+    let has_negative = d1 < -EPSILON || d2 < -EPSILON || d3 < -EPSILON;
+    let has_positive = d1 > EPSILON || d2 > EPSILON || d3 > EPSILON;
+
+    !(has_negative && has_positive)
 }
 
 /// Triangle with additional geometric properties
@@ -235,16 +485,16 @@ impl Triangle {
 
     /// Calculate the perimeter
     pub fn perimeter(&self) -> f64 {
-        nalgebra::distance(&self.a, &self.b) + 
-        nalgebra::distance(&self.b, &self.c) + 
-        nalgebra::distance(&self.c, &self.a)
+        crate::ops::distance(self.a, self.b) +
+        crate::ops::distance(self.b, self.c) +
+        crate::ops::distance(self.c, self.a)
     }
 
     /// Calculate the circumradius
     pub fn circumradius(&self) -> f64 {
-        let a = nalgebra::distance(&self.b, &self.c);
-        let b = nalgebra::distance(&self.c, &self.a);
-        let c = nalgebra::distance(&self.a, &self.b);
+        let a = crate::ops::distance(self.b, self.c);
+        let b = crate::ops::distance(self.c, self.a);
+        let c = crate::ops::distance(self.a, self.b);
         
         let area = self.area();
         if area < EPSILON {
@@ -268,21 +518,23 @@ impl Triangle {
 
     /// Check if the triangle is right-angled
     pub fn is_right_angled(&self, tolerance: f64) -> bool {
-        let a = nalgebra::distance(&self.b, &self.c);
-        let b = nalgebra::distance(&self.c, &self.a);
-        let c = nalgebra::distance(&self.a, &self.b);
+        use crate::ops::FloatPow;
+
+        let a = crate::ops::distance(self.b, self.c);
+        let b = crate::ops::distance(self.c, self.a);
+        let c = crate::ops::distance(self.a, self.b);
 
         let sides = [a, b, c];
         for i in 0..3 {
             let hypotenuse = sides[i];
             let leg1 = sides[(i + 1) % 3];
             let leg2 = sides[(i + 2) % 3];
-            
-            if (hypotenuse * hypotenuse - leg1 * leg1 - leg2 * leg2).abs() < tolerance {
+
+            if (hypotenuse.squared() - leg1.squared() - leg2.squared()).abs() < tolerance {
                 return true;
             }
         }
-        
+
         false
     }
 }
@@ -366,4 +618,90 @@ mod tests {
         ]);
         assert!(!l_shape.is_convex());
     }
+
+    #[test]
+    fn test_polygon_triangulate_square_yields_two_triangles_covering_the_area() {
+        let square = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ]);
+        let triangles = square.triangulate();
+        assert_eq!(triangles.len(), 2);
+        let total_area: f64 = triangles.iter().map(|t| t.area()).sum();
+        assert_abs_diff_eq!(total_area, square.area(), epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_polygon_triangulate_handles_concave_l_shape() {
+        let l_shape = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ]);
+        let triangles = l_shape.triangulate();
+        assert_eq!(triangles.len(), 4);
+        let total_area: f64 = triangles.iter().map(|t| t.area()).sum();
+        assert_abs_diff_eq!(total_area, l_shape.area(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_polygon_triangulate_degenerate_input_is_empty() {
+        let line = Polygon::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)]);
+        assert!(line.triangulate().is_empty());
+    }
+
+    #[test]
+    fn test_polygon_label_point_of_square_is_the_center() {
+        let square = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ]);
+        let label = square.label_point(0.01);
+        assert_abs_diff_eq!(label.x, 2.0, epsilon = 0.05);
+        assert_abs_diff_eq!(label.y, 2.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn test_polygon_label_point_of_l_shape_lands_inside() {
+        let l_shape = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ]);
+        // The centroid of this L falls in the notch that was carved out
+        assert!(l_shape.signed_distance(l_shape.centroid()) < 0.0);
+
+        let label = l_shape.label_point(0.01);
+        assert!(l_shape.signed_distance(label) > 0.0);
+    }
+
+    #[test]
+    fn test_polygon_label_point_degenerate_falls_back_to_centroid() {
+        let line = Polygon::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)]);
+        assert_eq!(line.label_point(0.01), line.centroid());
+    }
+
+    #[test]
+    fn test_polygon_triangulate_handles_clockwise_winding() {
+        let square_cw = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(0.0, 2.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.0, 0.0),
+        ]);
+        let triangles = square_cw.triangulate();
+        assert_eq!(triangles.len(), 2);
+        let total_area: f64 = triangles.iter().map(|t| t.area()).sum();
+        assert_abs_diff_eq!(total_area, square_cw.area(), epsilon = EPSILON);
+    }
 }
\ No newline at end of file
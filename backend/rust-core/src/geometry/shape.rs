@@ -0,0 +1,275 @@
+//! A uniform interface over the primitive shapes
+//!
+//! `Arc`, `Segment`, `Triangle`, and `Polygon` each grew their own ad-hoc
+//! `area`/`length` methods as they were added, so there was no common way to
+//! bound a shape, measure its boundary, or test point containment without
+//! matching on which primitive it happened to be. `Shape` gives callers one
+//! vocabulary for all of them. The curved primitive, `Arc`, has no exact
+//! piecewise-linear form, so it satisfies `perimeter`/`winding` by first
+//! flattening itself into a chord polyline accurate to a caller-chosen
+//! `tolerance` (see `Arc::to_polyline`) and then measuring that instead.
+
+use nalgebra::Point2;
+
+use super::angle::Angle;
+use super::primitives::{Arc, Polygon, Segment, Triangle, EPSILON};
+use crate::spatial_index::Aabb;
+
+/// A shape that can report its extent, boundary, area, and point containment
+pub trait Shape {
+    /// The smallest axis-aligned box enclosing this shape
+    fn bounding_box(&self) -> Aabb;
+
+    /// The boundary length. Exact for straight-edged shapes; for `Arc`,
+    /// accurate to within `tolerance` since it is measured along a flattened polyline
+    fn perimeter(&self, tolerance: f64) -> f64;
+
+    /// The enclosed area. Zero for shapes with no interior, such as a bare `Segment` or `Arc`
+    fn area(&self) -> f64;
+
+    /// The winding number of `pt` around this shape's boundary: zero outside,
+    /// nonzero inside. `tolerance` governs how close to the boundary counts as a crossing
+    fn winding(&self, pt: &Point2<f64>, tolerance: f64) -> i32;
+}
+
+impl Arc {
+    /// Subdivide this arc's sweep into a polyline whose maximum chord-to-arc
+    /// sag stays under `tolerance`. For a circular arc the sag over a step
+    /// angle `θ` is `radius*(1 - cos(θ/2))`, so the largest safe step is
+    /// `θ_max = 2*acos(1 - tolerance/radius)`.
+    pub fn to_polyline(&self, tolerance: f64) -> Vec<Point2<f64>> {
+        let sweep = (self.end_angle - self.start_angle).as_radians().abs();
+        if sweep < EPSILON || self.radius < EPSILON {
+            return vec![self.point_at_angle(self.start_angle)];
+        }
+
+        let ratio = (1.0 - tolerance / self.radius).clamp(-1.0, 1.0);
+        // Guard against a zero (or negative) step from a tolerance of 0: still
+        // flattens finely, just not into an unbounded number of segments
+        let max_step = (2.0 * crate::ops::acos(ratio)).max(1e-9);
+        let segments = (sweep / max_step).ceil().max(1.0) as usize;
+
+        (0..=segments)
+            .map(|i| {
+                let t = i as f64 / segments as f64;
+                self.point_at_angle(self.start_angle + (self.end_angle - self.start_angle) * t)
+            })
+            .collect()
+    }
+}
+
+impl Shape for Arc {
+    fn bounding_box(&self) -> Aabb {
+        // Exact rather than flattened: the box only needs the endpoints plus
+        // whichever axis-aligned extrema (0, π/2, π, 3π/2) the sweep passes through
+        let mut candidates = vec![self.point_at_angle(self.start_angle), self.point_at_angle(self.end_angle)];
+        for cardinal in [0.0, std::f64::consts::FRAC_PI_2, std::f64::consts::PI, 3.0 * std::f64::consts::FRAC_PI_2] {
+            let cardinal = Angle::radians(cardinal);
+            if self.contains_angle(cardinal) {
+                candidates.push(self.point_at_angle(cardinal));
+            }
+        }
+        Aabb::of_points(&candidates)
+    }
+
+    fn perimeter(&self, tolerance: f64) -> f64 {
+        let polyline = self.to_polyline(tolerance);
+        polyline.windows(2).map(|pair| crate::ops::distance(pair[0], pair[1])).sum()
+    }
+
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn winding(&self, pt: &Point2<f64>, tolerance: f64) -> i32 {
+        Polygon::new(self.to_polyline(tolerance)).winding(pt, tolerance)
+    }
+}
+
+impl Shape for Segment {
+    fn bounding_box(&self) -> Aabb {
+        Aabb::of_points(&[self.start, self.end])
+    }
+
+    fn perimeter(&self, _tolerance: f64) -> f64 {
+        self.length()
+    }
+
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn winding(&self, _pt: &Point2<f64>, _tolerance: f64) -> i32 {
+        // A bare segment encloses no area, so nothing is ever "inside" it
+        0
+    }
+}
+
+impl Shape for Triangle {
+    fn bounding_box(&self) -> Aabb {
+        Aabb::of_points(&[self.a, self.b, self.c])
+    }
+
+    fn perimeter(&self, _tolerance: f64) -> f64 {
+        self.perimeter()
+    }
+
+    fn area(&self) -> f64 {
+        self.area()
+    }
+
+    fn winding(&self, pt: &Point2<f64>, tolerance: f64) -> i32 {
+        let cross_ab = cross(self.a, self.b, *pt);
+        let cross_bc = cross(self.b, self.c, *pt);
+        let cross_ca = cross(self.c, self.a, *pt);
+
+        let positive = cross_ab >= -tolerance && cross_bc >= -tolerance && cross_ca >= -tolerance;
+        let negative = cross_ab <= tolerance && cross_bc <= tolerance && cross_ca <= tolerance;
+
+        if !positive && !negative {
+            return 0;
+        }
+
+        if cross_ab + cross_bc + cross_ca >= 0.0 {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl Shape for Polygon {
+    fn bounding_box(&self) -> Aabb {
+        Aabb::of_points(&self.vertices)
+    }
+
+    fn perimeter(&self, _tolerance: f64) -> f64 {
+        let n = self.vertices.len();
+        if n < 2 {
+            return 0.0;
+        }
+        (0..n).map(|i| crate::ops::distance(self.vertices[i], self.vertices[(i + 1) % n])).sum()
+    }
+
+    fn area(&self) -> f64 {
+        self.area()
+    }
+
+    fn winding(&self, pt: &Point2<f64>, tolerance: f64) -> i32 {
+        let n = self.vertices.len();
+        if n < 3 {
+            return 0;
+        }
+
+        let mut winding_number = 0;
+        for i in 0..n {
+            let v1 = self.vertices[i];
+            let v2 = self.vertices[(i + 1) % n];
+            if v1.y <= pt.y {
+                if v2.y > pt.y && is_left(v1, v2, *pt) > tolerance {
+                    winding_number += 1;
+                }
+            } else if v2.y <= pt.y && is_left(v1, v2, *pt) < -tolerance {
+                winding_number -= 1;
+            }
+        }
+        winding_number
+    }
+}
+
+/// The cross product `(b - o) x (c - o)`, positive when `o -> b -> c` turns left
+fn cross(o: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> f64 {
+    (b.x - o.x) * (c.y - o.y) - (b.y - o.y) * (c.x - o.x)
+}
+
+/// Whether `pt` is to the left of the directed line `a -> b`: positive for
+/// left, negative for right, zero when collinear
+fn is_left(a: Point2<f64>, b: Point2<f64>, pt: Point2<f64>) -> f64 {
+    cross(a, b, pt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_arc_to_polyline_respects_sag_tolerance() {
+        let quarter_circle = Arc::new(Point2::new(0.0, 0.0), 10.0, 0.0, std::f64::consts::FRAC_PI_2);
+        let polyline = quarter_circle.to_polyline(0.01);
+
+        for pair in polyline.windows(2) {
+            let midpoint = Point2::new((pair[0].x + pair[1].x) / 2.0, (pair[0].y + pair[1].y) / 2.0);
+            let sag = (nalgebra::distance(&Point2::new(0.0, 0.0), &midpoint) - 10.0).abs();
+            assert!(sag <= 0.01 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_arc_to_polyline_degenerate_sweep_is_single_point() {
+        let point_arc = Arc::new(Point2::new(0.0, 0.0), 5.0, 0.3, 0.3);
+        assert_eq!(point_arc.to_polyline(0.01).len(), 1);
+    }
+
+    #[test]
+    fn test_arc_bounding_box_covers_full_circle() {
+        let full_circle = Arc::new(Point2::new(1.0, 1.0), 2.0, 0.0, 2.0 * std::f64::consts::PI);
+        let bounds = full_circle.bounding_box();
+        assert_abs_diff_eq!(bounds.min.x, -1.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(bounds.min.y, -1.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(bounds.max.x, 3.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(bounds.max.y, 3.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_arc_perimeter_approaches_exact_arc_length_as_tolerance_tightens() {
+        let half_circle = Arc::new(Point2::new(0.0, 0.0), 1.0, 0.0, std::f64::consts::PI);
+        let flattened = half_circle.perimeter(1e-6);
+        assert_abs_diff_eq!(flattened, half_circle.length(), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_segment_shape_has_zero_area_and_winding() {
+        let segment = Segment::new(Point2::new(0.0, 0.0), Point2::new(3.0, 4.0));
+        assert_abs_diff_eq!(Shape::area(&segment), 0.0, epsilon = EPSILON);
+        assert_eq!(segment.winding(&Point2::new(1.0, 1.0), EPSILON), 0);
+        assert_abs_diff_eq!(segment.perimeter(0.0), 5.0, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_winding_detects_interior_and_exterior_points() {
+        let triangle = Triangle::new(Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 4.0));
+        assert_ne!(triangle.winding(&Point2::new(1.0, 1.0), EPSILON), 0);
+        assert_eq!(triangle.winding(&Point2::new(10.0, 10.0), EPSILON), 0);
+    }
+
+    #[test]
+    fn test_polygon_winding_handles_concave_l_shape() {
+        let l_shape = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ]);
+
+        // Inside the lower arm of the L
+        assert_ne!(l_shape.winding(&Point2::new(1.5, 0.5), EPSILON), 0);
+        // Inside the notch carved out of the upper-right corner
+        assert_eq!(l_shape.winding(&Point2::new(1.5, 1.5), EPSILON), 0);
+    }
+
+    #[test]
+    fn test_polygon_bounding_box_matches_vertex_extent() {
+        let square = Polygon::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ]);
+        let bounds = square.bounding_box();
+        assert_abs_diff_eq!(bounds.min.x, 0.0, epsilon = EPSILON);
+        assert_abs_diff_eq!(bounds.max.x, 2.0, epsilon = EPSILON);
+    }
+}
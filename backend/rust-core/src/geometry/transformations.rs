@@ -1,7 +1,10 @@
 //! Geometric transformations and symmetry operations
 
-use nalgebra::{Matrix3, Point2, Vector2};
+use nalgebra::{Matrix2, Matrix3, Point2, Vector2};
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+use crate::spatial_index::Aabb;
 
 /// A 2D transformation matrix
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -123,6 +126,72 @@ impl Transform2D {
         Vector2::new(transformed.x, transformed.y)
     }
 
+    /// Apply this transform to every point in `points`, returning the
+    /// transformed copies. See `transform_points_mut` for why this hoists
+    /// the matrix entries out of the loop instead of calling
+    /// `transform_point` per element.
+    pub fn transform_points(&self, points: &[Point2<f64>]) -> Vec<Point2<f64>> {
+        let mut out = points.to_vec();
+        self.transform_points_mut(&mut out);
+        out
+    }
+
+    /// In-place batch version of `transform_point`. Rebuilding a homogeneous
+    /// `Vector3` and multiplying by the full 3x3 matrix per point is wasted
+    /// work for a buffer of thousands of polygon/point-cloud points — this
+    /// hoists the six matrix entries that actually matter into locals once,
+    /// so the loop body is a straight sequence of multiply-adds the
+    /// autovectorizer can turn into SIMD instructions on its own. Built with
+    /// the `simd` feature, it instead runs an explicit `wide::f64x4` path
+    /// over four points at a time.
+    #[cfg(not(feature = "simd"))]
+    pub fn transform_points_mut(&self, points: &mut [Point2<f64>]) {
+        let (m00, m01, m02) = (self.matrix[(0, 0)], self.matrix[(0, 1)], self.matrix[(0, 2)]);
+        let (m10, m11, m12) = (self.matrix[(1, 0)], self.matrix[(1, 1)], self.matrix[(1, 2)]);
+
+        for p in points.iter_mut() {
+            let (x, y) = (p.x, p.y);
+            p.x = m00 * x + m01 * y + m02;
+            p.y = m10 * x + m11 * y + m12;
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn transform_points_mut(&self, points: &mut [Point2<f64>]) {
+        let (m00, m01, m02) = (self.matrix[(0, 0)], self.matrix[(0, 1)], self.matrix[(0, 2)]);
+        let (m10, m11, m12) = (self.matrix[(1, 0)], self.matrix[(1, 1)], self.matrix[(1, 2)]);
+        simd::affine_transform_points_mut(points, m00, m01, m02, m10, m11, m12);
+    }
+
+    /// Apply this transform to every vector in `vectors`, returning the
+    /// transformed copies. Vectors ignore translation, unlike points.
+    pub fn transform_vectors(&self, vectors: &[Vector2<f64>]) -> Vec<Vector2<f64>> {
+        let mut out = vectors.to_vec();
+        self.transform_vectors_mut(&mut out);
+        out
+    }
+
+    /// In-place batch version of `transform_vector`; see
+    /// `transform_points_mut` for why the loop is shaped this way.
+    #[cfg(not(feature = "simd"))]
+    pub fn transform_vectors_mut(&self, vectors: &mut [Vector2<f64>]) {
+        let (m00, m01) = (self.matrix[(0, 0)], self.matrix[(0, 1)]);
+        let (m10, m11) = (self.matrix[(1, 0)], self.matrix[(1, 1)]);
+
+        for v in vectors.iter_mut() {
+            let (x, y) = (v.x, v.y);
+            v.x = m00 * x + m01 * y;
+            v.y = m10 * x + m11 * y;
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    pub fn transform_vectors_mut(&self, vectors: &mut [Vector2<f64>]) {
+        let (m00, m01) = (self.matrix[(0, 0)], self.matrix[(0, 1)]);
+        let (m10, m11) = (self.matrix[(1, 0)], self.matrix[(1, 1)]);
+        simd::affine_transform_vectors_mut(vectors, m00, m01, m10, m11);
+    }
+
     /// Get the inverse transformation
     pub fn inverse(&self) -> Option<Self> {
         self.matrix.try_inverse().map(|inv_matrix| Self {
@@ -146,6 +215,189 @@ impl Transform2D {
         let det = self.determinant();
         (det - 1.0).abs() < 1e-10 || (det + 1.0).abs() < 1e-10
     }
+
+    fn basis_columns(&self) -> (Vector2<f64>, Vector2<f64>) {
+        (
+            Vector2::new(self.matrix[(0, 0)], self.matrix[(1, 0)]),
+            Vector2::new(self.matrix[(0, 1)], self.matrix[(1, 1)]),
+        )
+    }
+
+    /// The translation component: the matrix's last column
+    pub fn get_translation(&self) -> Vector2<f64> {
+        Vector2::new(self.matrix[(0, 2)], self.matrix[(1, 2)])
+    }
+
+    /// The rotation angle, recovered as the angle of the transformed x-axis
+    pub fn get_rotation(&self) -> f64 {
+        self.matrix[(1, 0)].atan2(self.matrix[(0, 0)])
+    }
+
+    /// The lengths of the two basis columns, with the y-scale's sign
+    /// flipped when this transform reverses orientation
+    pub fn get_scale(&self) -> Vector2<f64> {
+        let (col0, col1) = self.basis_columns();
+        let sy = if self.determinant() < 0.0 { -col1.norm() } else { col1.norm() };
+        Vector2::new(col0.norm(), sy)
+    }
+
+    /// The angle between the two basis vectors, minus π/2 — zero for an
+    /// unsheared (orthogonal) basis
+    pub fn get_skew(&self) -> f64 {
+        let (col0, col1) = self.basis_columns();
+        let cos_theta = (col0.dot(&col1) / (col0.norm() * col1.norm())).clamp(-1.0, 1.0);
+        cos_theta.acos() - std::f64::consts::FRAC_PI_2
+    }
+
+    /// Rebuild a transform from its decomposed translation, rotation,
+    /// (x, y) scale, and skew — the inverse of `get_translation`/
+    /// `get_rotation`/`get_scale`/`get_skew`
+    pub fn from_components(translation: Vector2<f64>, rotation: f64, scale: Vector2<f64>, skew: f64) -> Self {
+        let col0 = scale.x * Vector2::new(rotation.cos(), rotation.sin());
+        let perp_angle = rotation + std::f64::consts::FRAC_PI_2 + skew;
+        let col1 = scale.y * Vector2::new(perp_angle.cos(), perp_angle.sin());
+
+        let matrix = Matrix3::new(
+            col0.x, col1.x, translation.x,
+            col0.y, col1.y, translation.y,
+            0.0, 0.0, 1.0,
+        );
+        Self { matrix }
+    }
+
+    /// Strip scale and skew from the 2x2 basis via Gram-Schmidt,
+    /// keeping only rotation (and reflection, if this transform had one)
+    pub fn orthonormalize(&self) -> Self {
+        let (col0, col1) = self.basis_columns();
+        let u0 = col0.normalize();
+
+        let projected = col1.dot(&u0);
+        let remainder = col1 - projected * u0;
+        let u1 = if remainder.norm() > 1e-12 {
+            remainder.normalize()
+        } else {
+            // col1 was parallel to col0 (total shear collapse): fall back
+            // to the perpendicular of u0, preserving orientation
+            let perpendicular = Vector2::new(-u0.y, u0.x);
+            if self.determinant() < 0.0 { -perpendicular } else { perpendicular }
+        };
+
+        let mut matrix = Matrix3::identity();
+        matrix[(0, 0)] = u0.x;
+        matrix[(1, 0)] = u0.y;
+        matrix[(0, 1)] = u1.x;
+        matrix[(1, 1)] = u1.y;
+        matrix[(0, 2)] = self.matrix[(0, 2)];
+        matrix[(1, 2)] = self.matrix[(1, 2)];
+        Self { matrix }
+    }
+
+    /// Smoothly interpolate toward `other` at `t` (0 = self, 1 = other) by
+    /// decomposing both into translation/rotation/scale and lerping each
+    /// component, rather than lerping the raw matrices, which would produce
+    /// shear and shrink artifacts partway through a rotation. The rotation
+    /// is interpolated along its shortest angular path. Skew is dropped, as
+    /// animated transforms are rarely sheared and the decomposed components
+    /// this is built from (translation/rotation/scale) don't need it.
+    pub fn interpolate_with(&self, other: &Transform2D, t: f64) -> Self {
+        let translation = self.get_translation().lerp(&other.get_translation(), t);
+        let scale = self.get_scale().lerp(&other.get_scale(), t);
+
+        let mut delta_angle = other.get_rotation() - self.get_rotation();
+        delta_angle = (delta_angle + std::f64::consts::PI).rem_euclid(2.0 * std::f64::consts::PI) - std::f64::consts::PI;
+        let rotation = self.get_rotation() + delta_angle * t;
+
+        Self::from_components(translation, rotation, scale, 0.0)
+    }
+
+    /// Classify this transform back into a `Symmetry`, the reverse of
+    /// `Symmetry::to_transform`. Returns `None` for anything that isn't a
+    /// rigid motion. A proper motion (det ≈ +1) is a `Translation` when the
+    /// 2x2 part is the identity, otherwise a `Rotation` — its center found
+    /// by solving the fixed-point equation `(I - R) * center = translation`
+    /// — classified as `PointSymmetry` when the angle is ≈π. An
+    /// orientation-reversing motion (det ≈ -1) is a reflection or glide: the
+    /// mirror direction is the eigenvector for eigenvalue +1 of the
+    /// (always-symmetric) 2x2 part, and the translation splits into a
+    /// component along that direction (the glide amount) and one
+    /// perpendicular to it (which shifts the mirror line off the origin).
+    pub fn classify(&self) -> Option<Symmetry> {
+        let (col0, col1) = self.basis_columns();
+        // `is_rigid` only checks the determinant; a rigid motion also needs
+        // orthonormal basis columns
+        let orthonormal = (col0.norm() - 1.0).abs() < 1e-9
+            && (col1.norm() - 1.0).abs() < 1e-9
+            && col0.dot(&col1).abs() < 1e-9;
+        if !self.is_rigid() || !orthonormal {
+            return None;
+        }
+
+        let translation = self.get_translation();
+
+        if self.determinant() > 0.0 {
+            let is_identity_2x2 =
+                (col0 - Vector2::new(1.0, 0.0)).norm() < 1e-9 && (col1 - Vector2::new(0.0, 1.0)).norm() < 1e-9;
+            if is_identity_2x2 {
+                return Some(Symmetry::Translation { vector: translation });
+            }
+
+            let angle = self.get_rotation();
+            let basis = Matrix2::new(col0.x, col1.x, col0.y, col1.y);
+            let fixed_point_matrix = Matrix2::identity() - basis;
+            let center_vec = fixed_point_matrix.try_inverse()? * translation;
+            let center = Point2::new(center_vec.x, center_vec.y);
+
+            return if (angle.abs() - std::f64::consts::PI).abs() < 1e-9 {
+                Some(Symmetry::PointSymmetry { center })
+            } else {
+                Some(Symmetry::Rotation { center, angle })
+            };
+        }
+
+        // Orientation-reversing: the 2x2 part is symmetric, col0 = (a, b),
+        // col1 = (b, -a), with eigenvalues +1 (mirror direction) and -1.
+        let a = col0.x;
+        let b = col0.y;
+        let candidate = Vector2::new(1.0 + a, b);
+        let direction = if candidate.norm() > 1e-9 { candidate } else { Vector2::new(b, 1.0 - a) };
+        let dir = direction.normalize();
+        let normal = Vector2::new(-dir.y, dir.x);
+
+        let along = translation.dot(&dir) * dir;
+        let perpendicular = translation - along;
+        let offset = perpendicular.dot(&normal) / 2.0;
+
+        let line_point1 = Point2::new(0.0, 0.0) + offset * normal;
+        let line_point2 = line_point1 + dir;
+
+        if along.norm() < 1e-9 {
+            Some(Symmetry::Reflection { line_point1, line_point2 })
+        } else {
+            Some(Symmetry::GlideReflection { line_point1, line_point2, translation: along })
+        }
+    }
+
+    /// Invert just the affine part (2x2 basis plus translation), rather
+    /// than the general 3x3 inverse `inverse()` computes. `None` if the
+    /// 2x2 basis is singular.
+    pub fn affine_inverse(&self) -> Option<Self> {
+        let basis = Matrix2::new(
+            self.matrix[(0, 0)], self.matrix[(0, 1)],
+            self.matrix[(1, 0)], self.matrix[(1, 1)],
+        );
+        let basis_inv = basis.try_inverse()?;
+        let translation = self.get_translation();
+        let translation_inv = -(basis_inv * translation);
+
+        let mut matrix = Matrix3::identity();
+        matrix[(0, 0)] = basis_inv[(0, 0)];
+        matrix[(0, 1)] = basis_inv[(0, 1)];
+        matrix[(1, 0)] = basis_inv[(1, 0)];
+        matrix[(1, 1)] = basis_inv[(1, 1)];
+        matrix[(0, 2)] = translation_inv.x;
+        matrix[(1, 2)] = translation_inv.y;
+        Some(Self { matrix })
+    }
 }
 
 /// Symmetry operations for geometric objects
@@ -239,6 +491,429 @@ pub fn regular_polygon_symmetries(center: Point2<f64>, n: usize) -> Vec<Symmetry
     symmetries
 }
 
+/// A finite group of `Transform2D`s closed under composition
+///
+/// Built by repeated composition of a set of generators until no new
+/// member appears, rather than enumerated by hand like
+/// `regular_polygon_symmetries` does — this is a real algebraic object,
+/// useful for orbit/tiling queries instead of a one-off list of operations.
+#[derive(Debug, Clone)]
+pub struct SymmetryGroup {
+    members: Vec<Transform2D>,
+}
+
+impl SymmetryGroup {
+    /// Generate the group closed under composition from `generators`,
+    /// comparing each candidate product against existing members with a
+    /// matrix tolerance of `1e-9`. Stops early once `max_order` members have
+    /// been found, so generators spanning an infinite group (e.g. a
+    /// rotation by an irrational multiple of π) terminate instead of
+    /// looping forever.
+    pub fn generate(generators: &[Transform2D], max_order: usize) -> Self {
+        let mut members = vec![Transform2D::identity()];
+        for g in generators {
+            if members.len() < max_order && !contains_transform(&members, g) {
+                members.push(g.clone());
+            }
+        }
+
+        let mut frontier_start = 0;
+        while frontier_start < members.len() && members.len() < max_order {
+            let frontier_end = members.len();
+            for i in frontier_start..frontier_end {
+                for g in generators {
+                    if members.len() >= max_order {
+                        break;
+                    }
+                    let product = members[i].compose(g);
+                    if !contains_transform(&members, &product) {
+                        members.push(product);
+                    }
+                }
+            }
+            frontier_start = frontier_end;
+        }
+
+        Self { members }
+    }
+
+    /// Number of distinct elements in the group
+    pub fn order(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether `transform` is (within tolerance) a member of this group
+    pub fn contains(&self, transform: &Transform2D) -> bool {
+        contains_transform(&self.members, transform)
+    }
+
+    /// Every distinct image of `point` under the group's elements
+    pub fn orbit(&self, point: Point2<f64>) -> Vec<Point2<f64>> {
+        let mut images: Vec<Point2<f64>> = Vec::new();
+        for member in &self.members {
+            let image = member.transform_point(point);
+            if !images.iter().any(|&p: &Point2<f64>| (p - image).norm() < 1e-9) {
+                images.push(image);
+            }
+        }
+        images
+    }
+}
+
+/// Explicit `f64x4` batch-transform path, built only with the `simd`
+/// feature. Requires the optional `wide` dependency; the scalar loops in
+/// `Transform2D::transform_points_mut`/`transform_vectors_mut` already
+/// autovectorize reasonably well on most targets, so this is an opt-in for
+/// targets or workloads where the compiler doesn't get there on its own.
+#[cfg(feature = "simd")]
+mod simd {
+    use super::{Point2, Vector2};
+    use wide::f64x4;
+
+    pub fn affine_transform_points_mut(points: &mut [Point2<f64>], m00: f64, m01: f64, m02: f64, m10: f64, m11: f64, m12: f64) {
+        let (vm00, vm01, vm02) = (f64x4::splat(m00), f64x4::splat(m01), f64x4::splat(m02));
+        let (vm10, vm11, vm12) = (f64x4::splat(m10), f64x4::splat(m11), f64x4::splat(m12));
+
+        let mut chunks = points.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let xs = f64x4::new([chunk[0].x, chunk[1].x, chunk[2].x, chunk[3].x]);
+            let ys = f64x4::new([chunk[0].y, chunk[1].y, chunk[2].y, chunk[3].y]);
+            let new_xs = (xs * vm00 + ys * vm01 + vm02).to_array();
+            let new_ys = (xs * vm10 + ys * vm11 + vm12).to_array();
+            for i in 0..4 {
+                chunk[i].x = new_xs[i];
+                chunk[i].y = new_ys[i];
+            }
+        }
+        for p in chunks.into_remainder() {
+            let (x, y) = (p.x, p.y);
+            p.x = m00 * x + m01 * y + m02;
+            p.y = m10 * x + m11 * y + m12;
+        }
+    }
+
+    pub fn affine_transform_vectors_mut(vectors: &mut [Vector2<f64>], m00: f64, m01: f64, m10: f64, m11: f64) {
+        let (vm00, vm01) = (f64x4::splat(m00), f64x4::splat(m01));
+        let (vm10, vm11) = (f64x4::splat(m10), f64x4::splat(m11));
+
+        let mut chunks = vectors.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let xs = f64x4::new([chunk[0].x, chunk[1].x, chunk[2].x, chunk[3].x]);
+            let ys = f64x4::new([chunk[0].y, chunk[1].y, chunk[2].y, chunk[3].y]);
+            let new_xs = (xs * vm00 + ys * vm01).to_array();
+            let new_ys = (xs * vm10 + ys * vm11).to_array();
+            for i in 0..4 {
+                chunk[i].x = new_xs[i];
+                chunk[i].y = new_ys[i];
+            }
+        }
+        for v in chunks.into_remainder() {
+            let (x, y) = (v.x, v.y);
+            v.x = m00 * x + m01 * y;
+            v.y = m10 * x + m11 * y;
+        }
+    }
+}
+
+fn matrices_approx_eq(a: &Matrix3<f64>, b: &Matrix3<f64>, tolerance: f64) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < tolerance)
+}
+
+fn contains_transform(members: &[Transform2D], transform: &Transform2D) -> bool {
+    members.iter().any(|m| matrices_approx_eq(&m.matrix, &transform.matrix, 1e-9))
+}
+
+/// A 2D periodic lattice spanned by two basis vectors, used to tile a
+/// region of the plane with copies of a wallpaper group's point-group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Lattice2D {
+    pub basis_a: Vector2<f64>,
+    pub basis_b: Vector2<f64>,
+}
+
+impl Lattice2D {
+    pub fn new(basis_a: Vector2<f64>, basis_b: Vector2<f64>) -> Self {
+        Self { basis_a, basis_b }
+    }
+
+    /// Express `p` in fractional cell coordinates `(i, j)` such that
+    /// `p == i * basis_a + j * basis_b`
+    fn to_fractional(&self, p: Point2<f64>) -> (f64, f64) {
+        let det = self.basis_a.x * self.basis_b.y - self.basis_a.y * self.basis_b.x;
+        let i = (p.x * self.basis_b.y - p.y * self.basis_b.x) / det;
+        let j = (self.basis_a.x * p.y - self.basis_a.y * p.x) / det;
+        (i, j)
+    }
+
+    /// The range of integer cell indices whose cells could possibly overlap
+    /// `region`, padded by one cell on each side so a cell straddling the
+    /// region's boundary isn't missed
+    fn index_range(&self, region: &Aabb) -> (RangeInclusive<i64>, RangeInclusive<i64>) {
+        let corners = [
+            Point2::new(region.min.x, region.min.y),
+            Point2::new(region.max.x, region.min.y),
+            Point2::new(region.min.x, region.max.y),
+            Point2::new(region.max.x, region.max.y),
+        ];
+        let fractional: Vec<(f64, f64)> = corners.iter().map(|&p| self.to_fractional(p)).collect();
+
+        let i_min = fractional.iter().map(|(i, _)| *i).fold(f64::INFINITY, f64::min).floor() as i64 - 1;
+        let i_max = fractional.iter().map(|(i, _)| *i).fold(f64::NEG_INFINITY, f64::max).ceil() as i64 + 1;
+        let j_min = fractional.iter().map(|(_, j)| *j).fold(f64::INFINITY, f64::min).floor() as i64 - 1;
+        let j_max = fractional.iter().map(|(_, j)| *j).fold(f64::NEG_INFINITY, f64::max).ceil() as i64 + 1;
+
+        (i_min..=i_max, j_min..=j_max)
+    }
+
+    /// The bounding box of the cell at index `(i, j)`
+    fn cell_bounds(&self, i: i64, j: i64) -> Aabb {
+        let origin = Point2::new(0.0, 0.0) + (i as f64) * self.basis_a + (j as f64) * self.basis_b;
+        let corners = [
+            origin,
+            origin + self.basis_a,
+            origin + self.basis_b,
+            origin + self.basis_a + self.basis_b,
+        ];
+        Aabb::of_points(&corners)
+    }
+}
+
+/// One of the 17 plane (wallpaper) symmetry groups
+///
+/// Each variant's `point_group_generators` gives the rotations/reflections
+/// around the cell origin that, composed with every lattice translation,
+/// generate the group. Groups that involve a glide (`Pg`, `Pgg`, `P4g`) fold
+/// the actual lattice's `basis_a` into their glide offset (half a lattice
+/// step along the mirror axis) so the glide composes to a true lattice
+/// translation for any cell, not just a unit-aligned one. Centered groups
+/// (`Cm`, `Cmm`) add the cell's centering translation, `(basis_a + basis_b) / 2`,
+/// as an extra generator rather than reusing `Pm`/`Pmm`'s generators outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WallpaperGroup {
+    P1,
+    P2,
+    Pm,
+    Pg,
+    Cm,
+    Pmm,
+    Pmg,
+    Pgg,
+    Cmm,
+    P4,
+    P4m,
+    P4g,
+    P3,
+    P3m1,
+    P31m,
+    P6,
+    P6m,
+}
+
+impl WallpaperGroup {
+    /// The point-group operations (around the lattice origin) that, along
+    /// with every lattice translation, generate this wallpaper group.
+    /// `cell` is only consulted by the glide (`Pg`/`Pgg`/`P4g`) and centered
+    /// (`Cm`/`Cmm`) variants, which fold its basis vectors into their offset.
+    pub fn point_group_generators(&self, cell: &Lattice2D) -> Vec<Transform2D> {
+        use std::f64::consts::PI;
+
+        let identity = Transform2D::identity();
+        let mirror_x = Transform2D::reflection_x();
+        let mirror_y = Transform2D::reflection_y();
+        let mirror_diag = Transform2D::reflection(Vector2::new(-1.0, 1.0));
+        // Half a step along the lattice vector the mirror runs parallel to
+        // (`basis_a`), so two composed glides land on an exact lattice
+        // translation for any cell, not just a unit-aligned one
+        let glide_offset = cell.basis_a * 0.5;
+        let glide_x = Transform2D::translation(glide_offset.x, glide_offset.y).compose(&mirror_x);
+        // The centering translation that distinguishes a centered lattice
+        // from a primitive one
+        let center_offset = (cell.basis_a + cell.basis_b) * 0.5;
+        let center = Transform2D::translation(center_offset.x, center_offset.y);
+
+        match self {
+            WallpaperGroup::P1 => vec![identity],
+            WallpaperGroup::P2 => vec![identity, Transform2D::rotation(PI)],
+            WallpaperGroup::Pm => vec![identity, mirror_x],
+            WallpaperGroup::Pg => vec![identity, glide_x],
+            WallpaperGroup::Cm => vec![identity, mirror_x, center.clone(), center.compose(&mirror_x)],
+            WallpaperGroup::Pmm => vec![identity, Transform2D::rotation(PI), mirror_x, mirror_y],
+            WallpaperGroup::Pmg => vec![identity, Transform2D::rotation(PI), mirror_x],
+            WallpaperGroup::Pgg => vec![identity, Transform2D::rotation(PI), glide_x],
+            WallpaperGroup::Cmm => vec![
+                identity,
+                Transform2D::rotation(PI),
+                mirror_x,
+                mirror_y,
+                center.clone(),
+                center.compose(&Transform2D::rotation(PI)),
+                center.compose(&mirror_x),
+                center.compose(&mirror_y),
+            ],
+            WallpaperGroup::P4 => vec![
+                identity,
+                Transform2D::rotation(PI / 2.0),
+                Transform2D::rotation(PI),
+                Transform2D::rotation(3.0 * PI / 2.0),
+            ],
+            WallpaperGroup::P4m => vec![
+                identity,
+                Transform2D::rotation(PI / 2.0),
+                Transform2D::rotation(PI),
+                Transform2D::rotation(3.0 * PI / 2.0),
+                mirror_x,
+                mirror_y,
+                mirror_diag,
+                Transform2D::reflection(Vector2::new(1.0, 1.0)),
+            ],
+            WallpaperGroup::P4g => vec![
+                identity,
+                Transform2D::rotation(PI / 2.0),
+                Transform2D::rotation(PI),
+                Transform2D::rotation(3.0 * PI / 2.0),
+                glide_x,
+            ],
+            WallpaperGroup::P3 => vec![
+                identity,
+                Transform2D::rotation(2.0 * PI / 3.0),
+                Transform2D::rotation(4.0 * PI / 3.0),
+            ],
+            WallpaperGroup::P3m1 => vec![
+                identity,
+                Transform2D::rotation(2.0 * PI / 3.0),
+                Transform2D::rotation(4.0 * PI / 3.0),
+                mirror_x,
+            ],
+            WallpaperGroup::P31m => vec![
+                identity,
+                Transform2D::rotation(2.0 * PI / 3.0),
+                Transform2D::rotation(4.0 * PI / 3.0),
+                mirror_diag,
+            ],
+            WallpaperGroup::P6 => vec![
+                identity,
+                Transform2D::rotation(PI / 3.0),
+                Transform2D::rotation(2.0 * PI / 3.0),
+                Transform2D::rotation(PI),
+                Transform2D::rotation(4.0 * PI / 3.0),
+                Transform2D::rotation(5.0 * PI / 3.0),
+            ],
+            WallpaperGroup::P6m => vec![
+                identity,
+                Transform2D::rotation(PI / 3.0),
+                Transform2D::rotation(2.0 * PI / 3.0),
+                Transform2D::rotation(PI),
+                Transform2D::rotation(4.0 * PI / 3.0),
+                Transform2D::rotation(5.0 * PI / 3.0),
+                mirror_x,
+                mirror_diag,
+            ],
+        }
+    }
+
+    /// Every transform that tiles `cell` over `region`: every lattice
+    /// translation whose cell overlaps `region`, composed with each of this
+    /// group's point-group generators, deduplicated by matrix.
+    pub fn tiling_transforms(&self, cell: &Lattice2D, region: Aabb) -> Vec<Transform2D> {
+        let generators = self.point_group_generators(cell);
+        let (i_range, j_range) = cell.index_range(&region);
+
+        let mut transforms = Vec::new();
+        for i in i_range {
+            for j in j_range.clone() {
+                let cell_bounds = cell.cell_bounds(i, j);
+                if !cell_bounds.intersects(&region) {
+                    continue;
+                }
+
+                let translation = (i as f64) * cell.basis_a + (j as f64) * cell.basis_b;
+                let translate = Transform2D::translation(translation.x, translation.y);
+
+                for generator in &generators {
+                    let combined = translate.compose(generator);
+                    if !contains_transform(&transforms, &combined) {
+                        transforms.push(combined);
+                    }
+                }
+            }
+        }
+
+        transforms
+    }
+}
+
+/// One of the 7 frieze (1D repeating strip) symmetry groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FriezeGroup {
+    P1,
+    P11g,
+    P1m1,
+    P11m,
+    P2,
+    P2mg,
+    P2mm,
+}
+
+impl FriezeGroup {
+    /// The point-group operations that, along with every translation by an
+    /// integer multiple of the strip's repeat vector, generate this group.
+    /// Mirrors are taken relative to the strip axis lying along the x-axis.
+    pub fn point_group_generators(&self) -> Vec<Transform2D> {
+        use std::f64::consts::PI;
+
+        let identity = Transform2D::identity();
+        let mirror_vertical = Transform2D::reflection_y(); // perpendicular to the strip axis
+        let mirror_horizontal = Transform2D::reflection_x(); // along the strip axis
+        let half_turn = Transform2D::rotation(PI);
+        let glide = Transform2D::translation(0.5, 0.0).compose(&mirror_horizontal);
+
+        match self {
+            FriezeGroup::P1 => vec![identity],
+            FriezeGroup::P11g => vec![identity, glide],
+            FriezeGroup::P1m1 => vec![identity, mirror_vertical],
+            FriezeGroup::P11m => vec![identity, mirror_horizontal],
+            FriezeGroup::P2 => vec![identity, half_turn],
+            FriezeGroup::P2mg => vec![identity, half_turn, mirror_vertical],
+            FriezeGroup::P2mm => vec![identity, half_turn, mirror_vertical, mirror_horizontal],
+        }
+    }
+
+    /// Every transform that tiles a 1D strip along the x-axis over
+    /// `region`'s x-extent: every integer multiple of `repeat` (the strip's
+    /// translation step, along the x-axis) whose unit cell overlaps
+    /// `region`, composed with each point-group generator. `repeat` is
+    /// expected to lie along the x-axis, matching the axis the mirror
+    /// generators above are defined relative to.
+    pub fn tiling_transforms(&self, repeat: Vector2<f64>, region: Aabb) -> Vec<Transform2D> {
+        let generators = self.point_group_generators();
+        let step = repeat.x;
+        if step.abs() < 1e-12 {
+            return Vec::new();
+        }
+
+        let i_min = (region.min.x / step).floor() as i64 - 1;
+        let i_max = (region.max.x / step).ceil() as i64 + 1;
+
+        let mut transforms = Vec::new();
+        for i in i_min..=i_max {
+            let x = step * (i as f64);
+            if x < region.min.x - step.abs() || x > region.max.x + step.abs() {
+                continue;
+            }
+
+            let translate = Transform2D::translation(x, 0.0);
+            for generator in &generators {
+                let combined = translate.compose(generator);
+                if !contains_transform(&transforms, &combined) {
+                    transforms.push(combined);
+                }
+            }
+        }
+
+        transforms
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,4 +1004,347 @@ mod tests {
         assert_eq!(rotations, 4);
         assert_eq!(reflections, 4);
     }
+
+    #[test]
+    fn test_symmetry_group_closure_generates_cyclic_group() {
+        let quarter_turn = Transform2D::rotation(std::f64::consts::PI / 2.0);
+        let group = SymmetryGroup::generate(&[quarter_turn], 100);
+        assert_eq!(group.order(), 4); // identity + three quarter-turns
+    }
+
+    #[test]
+    fn test_symmetry_group_caps_at_max_order() {
+        // An irrational-angle rotation generates an infinite group
+        let irrational_turn = Transform2D::rotation(1.0);
+        let group = SymmetryGroup::generate(&[irrational_turn], 6);
+        assert_eq!(group.order(), 6);
+    }
+
+    #[test]
+    fn test_symmetry_group_contains() {
+        let quarter_turn = Transform2D::rotation(std::f64::consts::PI / 2.0);
+        let group = SymmetryGroup::generate(&[quarter_turn], 100);
+        let half_turn = Transform2D::rotation(std::f64::consts::PI);
+        let eighth_turn = Transform2D::rotation(std::f64::consts::PI / 4.0);
+
+        assert!(group.contains(&half_turn));
+        assert!(!group.contains(&eighth_turn));
+    }
+
+    #[test]
+    fn test_symmetry_group_orbit_of_square() {
+        let quarter_turn = Transform2D::rotation(std::f64::consts::PI / 2.0);
+        let group = SymmetryGroup::generate(&[quarter_turn], 100);
+        let orbit = group.orbit(Point2::new(1.0, 0.0));
+        assert_eq!(orbit.len(), 4);
+
+        // The point on the axis of rotation has a trivial (single-point) orbit
+        let fixed_orbit = group.orbit(Point2::new(0.0, 0.0));
+        assert_eq!(fixed_orbit.len(), 1);
+    }
+
+    #[test]
+    fn test_wallpaper_p1_generators_are_identity_only() {
+        let cell = Lattice2D::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        let generators = WallpaperGroup::P1.point_group_generators(&cell);
+        assert_eq!(generators.len(), 1);
+        assert_eq!(generators[0], Transform2D::identity());
+    }
+
+    #[test]
+    fn test_wallpaper_p4m_has_eight_generators() {
+        // 4 rotations x (no mirror / mirror) = 8 point-group operations
+        let cell = Lattice2D::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        assert_eq!(WallpaperGroup::P4m.point_group_generators(&cell).len(), 8);
+    }
+
+    #[test]
+    fn test_wallpaper_pg_glide_scales_with_lattice_basis() {
+        let cell = Lattice2D::new(Vector2::new(2.0, 0.0), Vector2::new(0.0, 1.0));
+        let generators = WallpaperGroup::Pg.point_group_generators(&cell);
+        let glide = generators.iter().find(|t| **t != Transform2D::identity()).unwrap();
+
+        // Applying the glide twice should land on an exact lattice
+        // translation by `basis_a`, not the unit-aligned (1, 0) it would
+        // produce if the glide offset were hardcoded
+        let twice = glide.compose(glide);
+        let image = twice.transform_point(Point2::new(0.0, 0.0));
+        assert_abs_diff_eq!(image.x, 2.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(image.y, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_wallpaper_cm_includes_centering_translation() {
+        let cell = Lattice2D::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        let generators = WallpaperGroup::Cm.point_group_generators(&cell);
+
+        let has_centering = generators.iter().any(|t| {
+            let p = t.transform_point(Point2::new(0.0, 0.0));
+            (p.x - 0.5).abs() < 1e-9 && (p.y - 0.5).abs() < 1e-9
+        });
+        assert!(has_centering, "Cm should generate the (0.5, 0.5) centering point");
+    }
+
+    #[test]
+    fn test_wallpaper_tiling_transforms_covers_unit_square_region() {
+        let cell = Lattice2D::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        let region = Aabb { min: Point2::new(0.0, 0.0), max: Point2::new(2.0, 2.0) };
+
+        let transforms = WallpaperGroup::P1.tiling_transforms(&cell, region);
+        // A unit cell tiling a 2x2 region should produce at least the 3x3
+        // neighborhood of translations that can overlap it
+        assert!(transforms.len() >= 9);
+
+        // Every produced transform should actually map into, or adjacent to, the region
+        for transform in &transforms {
+            let origin_image = transform.transform_point(Point2::new(0.0, 0.0));
+            assert!(origin_image.x >= -1.0 && origin_image.x <= 3.0);
+            assert!(origin_image.y >= -1.0 && origin_image.y <= 3.0);
+        }
+    }
+
+    #[test]
+    fn test_wallpaper_p2_includes_half_turn_translated_copies() {
+        let cell = Lattice2D::new(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        let region = Aabb { min: Point2::new(0.0, 0.0), max: Point2::new(1.0, 1.0) };
+
+        let transforms = WallpaperGroup::P2.tiling_transforms(&cell, region);
+        let has_half_turn = transforms.iter().any(|t| {
+            let p = t.transform_point(Point2::new(1.0, 0.0));
+            (p.x - (-1.0)).abs() < 1e-9 && p.y.abs() < 1e-9
+        });
+        assert!(has_half_turn);
+    }
+
+    #[test]
+    fn test_frieze_p1_generators_are_identity_only() {
+        let generators = FriezeGroup::P1.point_group_generators();
+        assert_eq!(generators.len(), 1);
+    }
+
+    #[test]
+    fn test_frieze_p2mm_tiling_transforms_nonempty() {
+        let region = Aabb { min: Point2::new(0.0, -1.0), max: Point2::new(5.0, 1.0) };
+        let transforms = FriezeGroup::P2mm.tiling_transforms(Vector2::new(1.0, 0.0), region);
+        assert!(!transforms.is_empty());
+    }
+
+    #[test]
+    fn test_decompose_translation_rotation_scale() {
+        let transform = Transform2D::translation(3.0, 4.0)
+            .compose(&Transform2D::rotation(std::f64::consts::PI / 6.0))
+            .compose(&Transform2D::scaling(2.0, 3.0));
+
+        let translation = transform.get_translation();
+        assert_abs_diff_eq!(translation.x, 3.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(translation.y, 4.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_rotation(), std::f64::consts::PI / 6.0, epsilon = 1e-10);
+
+        let scale = transform.get_scale();
+        assert_abs_diff_eq!(scale.x, 2.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(scale.y, 3.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_skew(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_from_components_round_trips_decomposition() {
+        let translation = Vector2::new(-1.0, 5.0);
+        let rotation = std::f64::consts::PI / 5.0;
+        let scale = Vector2::new(1.5, 2.5);
+        let skew = 0.2;
+
+        let transform = Transform2D::from_components(translation, rotation, scale, skew);
+
+        assert_abs_diff_eq!(transform.get_translation().x, translation.x, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_translation().y, translation.y, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_rotation(), rotation, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_scale().x, scale.x, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_scale().y, scale.y, epsilon = 1e-10);
+        assert_abs_diff_eq!(transform.get_skew(), skew, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_orthonormalize_strips_scale_and_skew() {
+        let sheared = Transform2D::rotation(0.3).compose(&Transform2D::scaling(5.0, 0.2));
+        let orthonormalized = sheared.orthonormalize();
+
+        assert_abs_diff_eq!(orthonormalized.get_scale().x, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(orthonormalized.get_scale().y, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(orthonormalized.get_skew(), 0.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(orthonormalized.get_rotation(), 0.3, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_with_halfway_rotation_and_scale() {
+        let start = Transform2D::identity();
+        let end = Transform2D::rotation(std::f64::consts::PI / 2.0).compose(&Transform2D::scaling(3.0, 3.0));
+
+        let halfway = start.interpolate_with(&end, 0.5);
+        assert_abs_diff_eq!(halfway.get_rotation(), std::f64::consts::PI / 4.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(halfway.get_scale().x, 2.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(halfway.get_scale().y, 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_interpolate_with_endpoints_match_inputs() {
+        let start = Transform2D::translation(1.0, 2.0).compose(&Transform2D::rotation(0.3));
+        let end = Transform2D::translation(5.0, -1.0).compose(&Transform2D::rotation(1.8));
+
+        let at_start = start.interpolate_with(&end, 0.0);
+        let at_end = start.interpolate_with(&end, 1.0);
+
+        assert_abs_diff_eq!(at_start.get_translation().x, start.get_translation().x, epsilon = 1e-9);
+        assert_abs_diff_eq!(at_start.get_rotation(), start.get_rotation(), epsilon = 1e-9);
+        assert_abs_diff_eq!(at_end.get_translation().x, end.get_translation().x, epsilon = 1e-9);
+        assert_abs_diff_eq!(at_end.get_rotation(), end.get_rotation(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_with_takes_shortest_angular_path() {
+        // -170 degrees to 170 degrees should cross through 180, not back through 0
+        let start = Transform2D::rotation(-170.0_f64.to_radians());
+        let end = Transform2D::rotation(170.0_f64.to_radians());
+
+        let halfway = start.interpolate_with(&end, 0.5);
+        assert_abs_diff_eq!(halfway.get_rotation().abs(), std::f64::consts::PI, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_transform_points_matches_per_point_transform() {
+        let transform = Transform2D::translation(1.0, 2.0).compose(&Transform2D::rotation(0.5));
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(3.0, -2.0),
+            Point2::new(-1.5, 4.0),
+        ];
+
+        let batch = transform.transform_points(&points);
+        for (p, transformed) in points.iter().zip(batch.iter()) {
+            let expected = transform.transform_point(*p);
+            assert_abs_diff_eq!(transformed.x, expected.x, epsilon = 1e-10);
+            assert_abs_diff_eq!(transformed.y, expected.y, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_transform_points_mut_matches_transform_points() {
+        let transform = Transform2D::scaling(2.0, 3.0);
+        let mut points = vec![Point2::new(1.0, 1.0), Point2::new(2.0, -1.0), Point2::new(0.0, 5.0)];
+        let expected = transform.transform_points(&points);
+
+        transform.transform_points_mut(&mut points);
+
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert_abs_diff_eq!(p.x, e.x, epsilon = 1e-10);
+            assert_abs_diff_eq!(p.y, e.y, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_transform_vectors_ignores_translation() {
+        let transform = Transform2D::translation(100.0, -50.0).compose(&Transform2D::rotation(std::f64::consts::PI / 2.0));
+        let vectors = vec![Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)];
+
+        let transformed = transform.transform_vectors(&vectors);
+        assert_abs_diff_eq!(transformed[0].x, 0.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(transformed[0].y, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(transformed[1].x, -1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(transformed[1].y, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_classify_translation() {
+        let transform = Transform2D::translation(3.0, -2.0);
+        match transform.classify() {
+            Some(Symmetry::Translation { vector }) => {
+                assert_abs_diff_eq!(vector.x, 3.0, epsilon = 1e-9);
+                assert_abs_diff_eq!(vector.y, -2.0, epsilon = 1e-9);
+            }
+            other => panic!("expected Translation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_rotation_around_point() {
+        let center = Point2::new(2.0, 5.0);
+        let angle = std::f64::consts::PI / 3.0;
+        let transform = Transform2D::rotation_around_point(angle, center);
+
+        match transform.classify() {
+            Some(Symmetry::Rotation { center: found_center, angle: found_angle }) => {
+                assert_abs_diff_eq!(found_angle, angle, epsilon = 1e-9);
+                assert_abs_diff_eq!(found_center.x, center.x, epsilon = 1e-9);
+                assert_abs_diff_eq!(found_center.y, center.y, epsilon = 1e-9);
+            }
+            other => panic!("expected Rotation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_point_symmetry() {
+        let center = Point2::new(-1.0, 3.0);
+        let transform = Transform2D::rotation_around_point(std::f64::consts::PI, center);
+
+        match transform.classify() {
+            Some(Symmetry::PointSymmetry { center: found_center }) => {
+                assert_abs_diff_eq!(found_center.x, center.x, epsilon = 1e-9);
+                assert_abs_diff_eq!(found_center.y, center.y, epsilon = 1e-9);
+            }
+            other => panic!("expected PointSymmetry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_reflection_across_arbitrary_line() {
+        let p1 = Point2::new(1.0, 2.0);
+        let p2 = Point2::new(4.0, 6.0);
+        let transform = Transform2D::reflection_across_line(p1, p2);
+
+        let classified = transform.classify().expect("reflection should classify");
+        assert!(matches!(classified, Symmetry::Reflection { .. }));
+
+        let probe = Point2::new(-3.0, 7.0);
+        let expected = transform.transform_point(probe);
+        let actual = classified.apply_to_point(probe);
+        assert_abs_diff_eq!(actual.x, expected.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(actual.y, expected.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_classify_glide_reflection_round_trips() {
+        let original = Symmetry::GlideReflection {
+            line_point1: Point2::new(0.0, 0.0),
+            line_point2: Point2::new(1.0, 0.0),
+            translation: Vector2::new(2.5, 0.0),
+        };
+        let transform = original.to_transform();
+
+        let classified = transform.classify().expect("glide reflection should classify");
+        assert!(matches!(classified, Symmetry::GlideReflection { .. }));
+
+        let probe = Point2::new(3.0, -4.0);
+        let expected = transform.transform_point(probe);
+        let actual = classified.apply_to_point(probe);
+        assert_abs_diff_eq!(actual.x, expected.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(actual.y, expected.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_classify_returns_none_for_non_rigid() {
+        let transform = Transform2D::scaling(2.0, 3.0);
+        assert!(transform.classify().is_none());
+    }
+
+    #[test]
+    fn test_affine_inverse_undoes_transform() {
+        let transform = Transform2D::translation(2.0, -3.0).compose(&Transform2D::rotation(1.1));
+        let inverse = transform.affine_inverse().unwrap();
+        let point = Point2::new(4.0, 7.0);
+
+        let round_tripped = inverse.transform_point(transform.transform_point(point));
+        assert_abs_diff_eq!(round_tripped.x, point.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(round_tripped.y, point.y, epsilon = 1e-9);
+    }
 }
\ No newline at end of file
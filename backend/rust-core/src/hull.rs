@@ -0,0 +1,162 @@
+//! Convex and concave hull construction over a point cloud
+
+use nalgebra::Point2;
+
+/// Compute the convex hull of `points` using Andrew's monotone chain
+/// algorithm. Returns the hull vertices in counter-clockwise order.
+pub fn convex_hull(points: &[Point2<f64>]) -> Vec<Point2<f64>> {
+    let mut sorted: Vec<Point2<f64>> = points.to_vec();
+    sorted.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap().then(a.y.partial_cmp(&b.y).unwrap()));
+    sorted.dedup_by(|a, b| (a.x - b.x).abs() < f64::EPSILON && (a.y - b.y).abs() < f64::EPSILON);
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: Point2<f64>, a: Point2<f64>, b: Point2<f64>| {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    };
+
+    let mut lower: Vec<Point2<f64>> = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<Point2<f64>> = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// Compute a tighter, concave hull starting from the convex hull: repeatedly
+/// replace the longest boundary edge with two edges through the nearest
+/// interior point, as long as that edge is longer than `concavity`.
+pub fn concave_hull(points: &[Point2<f64>], concavity: f64) -> Vec<Point2<f64>> {
+    let mut hull = convex_hull(points);
+    if hull.len() < 3 {
+        return hull;
+    }
+
+    loop {
+        let n = hull.len();
+        let (longest_idx, longest_len) = (0..n)
+            .map(|i| {
+                let a = hull[i];
+                let b = hull[(i + 1) % n];
+                (i, nalgebra::distance(&a, &b))
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .unwrap();
+
+        if longest_len <= concavity {
+            break;
+        }
+
+        let a = hull[longest_idx];
+        let b = hull[(longest_idx + 1) % n];
+
+        // Find the point nearest this edge among those not already on the
+        // hull and on the edge's interior side. Only that half-plane is
+        // checked: the two new edges are not tested against the rest of the boundary.
+        let candidate = points
+            .iter()
+            .filter(|p| !hull.iter().any(|h| nalgebra::distance(h, p) < f64::EPSILON))
+            .filter(|p| is_left_of(a, b, **p))
+            .min_by(|p, q| {
+                let dp = point_to_segment_distance(**p, a, b);
+                let dq = point_to_segment_distance(**q, a, b);
+                dp.partial_cmp(&dq).unwrap()
+            });
+
+        match candidate {
+            Some(&p) => {
+                hull.insert(longest_idx + 1, p);
+            }
+            None => break, // no interior point available, keep this edge
+        }
+    }
+
+    hull
+}
+
+/// Is `p` on the interior side of directed edge `a -> b` (to the left of it,
+/// i.e. inside a counter-clockwise hull)?
+fn is_left_of(a: Point2<f64>, b: Point2<f64>, p: Point2<f64>) -> bool {
+    let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+    cross > 0.0
+}
+
+fn point_to_segment_distance(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.x * ab.x + ab.y * ab.y;
+    if len_sq < f64::EPSILON {
+        return nalgebra::distance(&p, &a);
+    }
+    let t = ((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / len_sq;
+    let t = t.clamp(0.0, 1.0);
+    let closest = Point2::new(a.x + t * ab.x, a.y + t * ab.y);
+    nalgebra::distance(&p, &closest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convex_hull_square_with_interior_point() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+            Point2::new(1.0, 1.0), // interior point, should be excluded
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+    }
+
+    #[test]
+    fn test_convex_hull_triangle() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 3);
+    }
+
+    #[test]
+    fn test_concave_hull_bites_into_a_notch() {
+        // A trapezoid with one unambiguously longest edge (length 12, vs.
+        // ~10.2 and 10 for the rest) and a notch point pulled in from its
+        // middle - the convex hull alone excludes the notch point entirely.
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(12.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+            Point2::new(6.0, 3.0), // notch point, well inside the convex hull
+        ];
+
+        let convex = convex_hull(&points);
+        assert_eq!(convex.len(), 4); // the notch point is excluded by the convex hull alone
+
+        // Below the longest edge's length (12) but above the next-longest
+        // (~10.2), so only that one edge gets split
+        let concave = concave_hull(&points, 11.0);
+        assert_eq!(concave.len(), 5, "concave hull should pick up the notch point the convex hull excluded");
+        assert!(concave.iter().any(|p| nalgebra::distance(p, &Point2::new(6.0, 3.0)) < f64::EPSILON));
+    }
+}
@@ -3,9 +3,18 @@
 use wasm_bindgen::prelude::*;
 
 pub mod geometry;
-pub mod construction; 
+pub mod construction;
+pub mod construction_graph;
 pub mod collection;
+pub mod encounter;
+pub mod hull;
+pub mod ops;
+pub mod script;
+pub mod serialization;
+pub mod spatial_index;
+pub mod triangulation;
 pub mod utils;
+pub mod wkt;
 
 #[wasm_bindgen]
 extern "C" {
@@ -27,6 +36,7 @@ pub fn main() {
 #[wasm_bindgen]
 pub struct GeometryEngine {
     construction_space: construction::ConstructionSpace,
+    collection: collection::ElementCollection,
 }
 
 #[wasm_bindgen]
@@ -36,6 +46,7 @@ impl GeometryEngine {
     pub fn new() -> Self {
         Self {
             construction_space: construction::ConstructionSpace::new(),
+            collection: collection::ElementCollection::new(),
         }
     }
 
@@ -62,13 +73,153 @@ impl GeometryEngine {
             .map_err(|e| e.to_string())
     }
 
+    /// Export the construction space as a WKT `GEOMETRYCOLLECTION`
+    #[wasm_bindgen]
+    pub fn export_wkt(&self) -> String {
+        wkt::export_construction(&self.construction_space)
+    }
+
+    /// Import geometries from a WKT string, returning the IDs of the new elements
+    #[wasm_bindgen]
+    pub fn import_wkt(&mut self, wkt: &str) -> std::result::Result<Vec<String>, String> {
+        crate::wkt::import_construction(&mut self.construction_space, wkt)
+    }
+
+    /// Export the construction space as a GeoJSON `FeatureCollection` string
+    #[wasm_bindgen]
+    pub fn export_geojson(&self) -> String {
+        serialization::to_geojson(&self.construction_space).to_string()
+    }
+
+    /// Import geometries from a GeoJSON `FeatureCollection` string, returning
+    /// the IDs of the new elements
+    #[wasm_bindgen]
+    pub fn import_geojson(&mut self, geojson: &str) -> std::result::Result<Vec<String>, String> {
+        let value: serde_json::Value = serde_json::from_str(geojson).map_err(|e| e.to_string())?;
+        serialization::from_geojson(&mut self.construction_space, &value)
+    }
+
+    /// Triangulate all points currently in the construction space, catching
+    /// each resulting triangle into the collection. Returns the IDs of the
+    /// newly caught `Triangle` elements.
+    #[wasm_bindgen]
+    pub fn triangulate(&mut self) -> Vec<String> {
+        let points: Vec<_> = self
+            .construction_space
+            .points()
+            .map(|p| p.position)
+            .collect();
+
+        let result = triangulation::delaunay(&points);
+
+        result
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let triangle_type = triangulation::classify_triangle_type(triangle);
+                let element = collection::ElementFactory::create_triangle(triangle_type);
+                let id = element.id.clone();
+                self.collection.catch_element(element);
+                id
+            })
+            .collect()
+    }
+
+    /// Compute the convex hull of all points in the construction space and
+    /// catch it as a `Polygon` element. Returns the new element's ID.
+    #[wasm_bindgen]
+    pub fn convex_hull(&mut self) -> std::result::Result<String, String> {
+        let points: Vec<_> = self
+            .construction_space
+            .points()
+            .map(|p| p.position)
+            .collect();
+
+        let vertices = hull::convex_hull(&points);
+        if vertices.len() < 3 {
+            return Err("Not enough points to form a hull".to_string());
+        }
+
+        let element = collection::ElementFactory::create_polygon(vertices.len());
+        let id = element.id.clone();
+        self.collection.catch_element(element);
+        Ok(id)
+    }
+
+    /// Compute a concave hull of all points in the construction space and
+    /// catch it as a `Polygon` element. Returns the new element's ID.
+    #[wasm_bindgen]
+    pub fn concave_hull(&mut self, concavity: f64) -> std::result::Result<String, String> {
+        let points: Vec<_> = self
+            .construction_space
+            .points()
+            .map(|p| p.position)
+            .collect();
+
+        let vertices = hull::concave_hull(&points, concavity);
+        if vertices.len() < 3 {
+            return Err("Not enough points to form a hull".to_string());
+        }
+
+        let element = collection::ElementFactory::create_polygon(vertices.len());
+        let id = element.id.clone();
+        self.collection.catch_element(element);
+        Ok(id)
+    }
+
+    /// Parse and execute a construction script, returning the IDs of every
+    /// element it created, in order. Errors report the offending line.
+    #[wasm_bindgen]
+    pub fn run_script(&mut self, src: &str) -> std::result::Result<Vec<String>, String> {
+        let statements = script::parse_script(src).map_err(|e| e.to_string())?;
+        script::run(&mut self.construction_space, &statements).map_err(|e| e.to_string())
+    }
+
+    /// Re-serialize the current construction as script text
+    #[wasm_bindgen]
+    pub fn dump_script(&self) -> String {
+        script::dump_script(&self.construction_space)
+    }
+
+    /// Roll a rarity-weighted encounter and catch it, returning the new element's ID
+    #[wasm_bindgen]
+    pub fn roll_encounter(&mut self, rng_seed: u64) -> String {
+        let element = self.collection.roll_encounter(rng_seed);
+        let id = element.id.clone();
+        self.collection.catch_element(element);
+        id
+    }
+
+    /// Move a base point to `(x, y)` and recompute every point derived from
+    /// it, in dependency order, turning the construction into a live,
+    /// draggable model instead of a one-shot calculation. Errors if
+    /// `point_id` names a constructed point, or if recomputation finds a
+    /// downstream construction has become degenerate.
+    #[wasm_bindgen]
+    pub fn move_point(&mut self, point_id: &str, x: f64, y: f64) -> std::result::Result<(), String> {
+        self.construction_space.move_point(point_id, x, y).map_err(|e| e.to_string())
+    }
+
+    /// Find the point nearest to `(x, y)` using a spatial index built over
+    /// the current construction, returning its ID. Returns `None` if the
+    /// construction space has no points, or none within `tolerance` of
+    /// `(x, y)` — e.g. when deduping a newly constructed intersection point
+    /// against ones already in the space.
+    #[wasm_bindgen]
+    pub fn nearest_point(&self, x: f64, y: f64, tolerance: f64) -> Option<String> {
+        let index = spatial_index::SpatialIndex::build(&self.construction_space, 1.0);
+        index
+            .nearest_point(&self.construction_space, nalgebra::Point2::new(x, y), tolerance)
+            .map(|point| point.id.clone())
+    }
+
     /// Get construction space info as JSON string
     #[wasm_bindgen]
     pub fn get_info(&self) -> String {
         format!("Points: {}, Lines: {}, Circles: {}", 
-                self.construction_space.points.len(),
-                self.construction_space.lines.len(), 
-                self.construction_space.circles.len())
+                self.construction_space.point_count(),
+                self.construction_space.line_count(), 
+                self.construction_space.circle_count())
     }
 }
 
@@ -0,0 +1,144 @@
+//! Float primitives routed through either `std` or `libm`, selected by the
+//! `libm` cargo feature
+//!
+//! `f64::sqrt` and the `Vector2`/`Point2` helpers built on it are
+//! correctly-rounded on essentially every target Rust supports, but that's a
+//! property of the platform's libm, not a language guarantee. Since
+//! constructed `Point`s are serialized and compared against a Python client
+//! running on a possibly different platform, that last bit matters: building
+//! with `--features libm` swaps in `libm`'s portable, bit-identical
+//! implementations everywhere this module is used instead of the host's own.
+
+use nalgebra::{Point2, Vector2};
+
+/// Square root, routed through `libm::sqrt` when the `libm` feature is enabled
+#[cfg(feature = "libm")]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// Euclidean distance between two points, using this module's `sqrt`
+pub fn distance(a: Point2<f64>, b: Point2<f64>) -> f64 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    sqrt(dx * dx + dy * dy)
+}
+
+/// The vector's length, using this module's `sqrt`
+pub fn norm(v: Vector2<f64>) -> f64 {
+    sqrt(v.x * v.x + v.y * v.y)
+}
+
+/// Unit vector in the direction of `v`, using this module's `sqrt`
+pub fn normalize(v: Vector2<f64>) -> Vector2<f64> {
+    let len = norm(v);
+    Vector2::new(v.x / len, v.y / len)
+}
+
+/// Sine, routed through `libm::sin` when the `libm` feature is enabled
+#[cfg(feature = "libm")]
+pub fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+/// Cosine, routed through `libm::cos` when the `libm` feature is enabled
+#[cfg(feature = "libm")]
+pub fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+/// Two-argument arctangent, routed through `libm::atan2` when the `libm` feature is enabled
+#[cfg(feature = "libm")]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+/// Arccosine, routed through `libm::acos` when the `libm` feature is enabled
+#[cfg(feature = "libm")]
+pub fn acos(x: f64) -> f64 {
+    libm::acos(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn acos(x: f64) -> f64 {
+    x.acos()
+}
+
+/// `f64::powi` replacement for small integer powers, since `libm` has no
+/// generic integer-power function. Plain multiplication is already
+/// bit-identical across platforms, so unlike the rest of this module these
+/// don't need a `libm` branch.
+pub trait FloatPow {
+    fn squared(self) -> f64;
+    fn cubed(self) -> f64;
+}
+
+impl FloatPow for f64 {
+    fn squared(self) -> f64 {
+        self * self
+    }
+
+    fn cubed(self) -> f64 {
+        self * self * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(Point2::new(0.0, 0.0), Point2::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_norm() {
+        assert_eq!(norm(Vector2::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v = normalize(Vector2::new(3.0, 4.0));
+        assert!((v.x - 0.6).abs() < 1e-12);
+        assert!((v.y - 0.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sin_cos_match_std() {
+        assert!((sin(std::f64::consts::FRAC_PI_2) - 1.0).abs() < 1e-12);
+        assert!((cos(0.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_atan2_and_acos() {
+        assert!((atan2(1.0, 1.0) - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert!((acos(1.0) - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_float_pow_squared_and_cubed() {
+        assert_eq!(2.0_f64.squared(), 4.0);
+        assert_eq!(2.0_f64.cubed(), 8.0);
+    }
+}
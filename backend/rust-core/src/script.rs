@@ -0,0 +1,351 @@
+//! A text construction-script DSL, parser, and replay engine
+//!
+//! Lets a Euclidean construction be authored and replayed as plain text
+//! instead of imperative calls into the construction space, e.g.:
+//!
+//! ```text
+//! point A 0 0
+//! point B 1 0
+//! circle c1 center A through B
+//! circle c2 center B through A
+//! intersect P c1 c2
+//! line AB from A to B
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::construction::{ConstructionSpace, ConstructionStep, Derivation};
+use crate::geometry::Point;
+
+/// A single parsed statement, bound to the source line it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocatedStatement {
+    pub line: usize,
+    pub statement: Statement,
+}
+
+/// The statements this DSL supports
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// `point <name> <x> <y>`
+    Point { name: String, x: f64, y: f64 },
+    /// `circle <name> center <center> through <through>`
+    Circle {
+        name: String,
+        center: String,
+        through: String,
+    },
+    /// `line <name> from <from> to <to>`
+    Line {
+        name: String,
+        from: String,
+        to: String,
+    },
+    /// `intersect <name> <obj1> <obj2>`
+    Intersect {
+        name: String,
+        obj1: String,
+        obj2: String,
+    },
+}
+
+/// A parse or execution error, always tied to the offending source line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parse a construction script into an ordered list of statements
+pub fn parse_script(src: &str) -> Result<Vec<LocatedStatement>, ScriptError> {
+    let mut statements = Vec::new();
+
+    for (idx, raw_line) in src.lines().enumerate() {
+        let line = idx + 1;
+        let trimmed = raw_line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let statement = parse_statement(line, trimmed)?;
+        statements.push(LocatedStatement { line, statement });
+    }
+
+    Ok(statements)
+}
+
+fn parse_statement(line: usize, text: &str) -> Result<Statement, ScriptError> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let err = |message: &str| ScriptError {
+        line,
+        message: message.to_string(),
+    };
+
+    match tokens.as_slice() {
+        ["point", name, x, y] => {
+            let x: f64 = x.parse().map_err(|_| err("invalid x coordinate"))?;
+            let y: f64 = y.parse().map_err(|_| err("invalid y coordinate"))?;
+            Ok(Statement::Point {
+                name: name.to_string(),
+                x,
+                y,
+            })
+        }
+        ["circle", name, "center", center, "through", through] => Ok(Statement::Circle {
+            name: name.to_string(),
+            center: center.to_string(),
+            through: through.to_string(),
+        }),
+        ["line", name, "from", from, "to", to] => Ok(Statement::Line {
+            name: name.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+        }),
+        ["intersect", name, obj1, obj2] => Ok(Statement::Intersect {
+            name: name.to_string(),
+            obj1: obj1.to_string(),
+            obj2: obj2.to_string(),
+        }),
+        [verb, ..] => Err(err(&format!("unknown statement verb '{}'", verb))),
+        [] => Err(err("empty statement")),
+    }
+}
+
+/// Execute parsed statements against a construction space, binding each
+/// statement's name to the element ID it produces. Returns the IDs of every
+/// element created, in execution order.
+pub fn run(
+    space: &mut ConstructionSpace,
+    statements: &[LocatedStatement],
+) -> Result<Vec<String>, ScriptError> {
+    let mut symbols: HashMap<String, String> = HashMap::new();
+    let mut ids = Vec::new();
+
+    for located in statements {
+        let line = located.line;
+        let err = |message: String| ScriptError { line, message };
+
+        let lookup = |symbols: &HashMap<String, String>, name: &str| {
+            symbols
+                .get(name)
+                .cloned()
+                .ok_or_else(|| err(format!("undefined name '{}'", name)))
+        };
+
+        let id = match &located.statement {
+            Statement::Point { name, x, y } => {
+                let id = space.add_point(Point::new(*x, *y, Some(name.clone())));
+                id
+            }
+            Statement::Circle {
+                name,
+                center,
+                through,
+            } => {
+                let center_id = lookup(&symbols, center)?;
+                let through_id = lookup(&symbols, through)?;
+                space
+                    .construct_circle(&center_id, &through_id, Some(name.clone()))
+                    .map_err(|e| err(e.to_string()))?
+            }
+            Statement::Line { name, from, to } => {
+                let from_id = lookup(&symbols, from)?;
+                let to_id = lookup(&symbols, to)?;
+                space
+                    .construct_line(&from_id, &to_id, Some(name.clone()))
+                    .map_err(|e| err(e.to_string()))?
+            }
+            Statement::Intersect { name, obj1, obj2 } => {
+                let obj1_id = lookup(&symbols, obj1)?;
+                let obj2_id = lookup(&symbols, obj2)?;
+                let points = space
+                    .find_intersections(&obj1_id, &obj2_id)
+                    .map_err(|e| err(e.to_string()))?;
+                points
+                    .first()
+                    .map(|p| p.id.clone())
+                    .ok_or_else(|| err(format!("'{}' and '{}' do not intersect", obj1, obj2)))?
+            }
+        };
+
+        symbols.insert(statement_name(&located.statement).to_string(), id.clone());
+        ids.push(id);
+    }
+
+    Ok(ids)
+}
+
+fn statement_name(statement: &Statement) -> &str {
+    match statement {
+        Statement::Point { name, .. } => name,
+        Statement::Circle { name, .. } => name,
+        Statement::Line { name, .. } => name,
+        Statement::Intersect { name, .. } => name,
+    }
+}
+
+/// Re-serialize a construction space's history back into script text, so
+/// proofs become shareable, editable files.
+pub fn dump_script(space: &ConstructionSpace) -> String {
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut point_counter = 0;
+    let mut line_counter = 0;
+    let mut circle_counter = 0;
+    let mut lines = Vec::new();
+
+    let mut name_for = |id: &str, label: &Option<String>, names: &mut HashMap<String, String>, counter: &mut usize, prefix: &str| -> String {
+        if let Some(existing) = names.get(id) {
+            return existing.clone();
+        }
+        let name = label.clone().unwrap_or_else(|| {
+            *counter += 1;
+            format!("{}{}", prefix, counter)
+        });
+        names.insert(id.to_string(), name.clone());
+        name
+    };
+
+    for step in &space.history {
+        match step {
+            ConstructionStep::AddPoint { point, derivation } => {
+                let name = name_for(&point.id, &point.label, &mut names, &mut point_counter, "P");
+                match derivation {
+                    // Only the DSL's `intersect` statement (which always binds the
+                    // first intersection point) round-trips losslessly, so that's
+                    // the one derivation dumped as such; anything else (e.g. a
+                    // parallel/perpendicular construction's offset point) falls
+                    // back to a bare `point`, same as an unconstrained base point.
+                    Some(Derivation::Intersection { obj1_id, obj2_id, index }) if *index == 0 => {
+                        let obj1 = names.get(obj1_id).cloned().unwrap_or_else(|| obj1_id.clone());
+                        let obj2 = names.get(obj2_id).cloned().unwrap_or_else(|| obj2_id.clone());
+                        lines.push(format!("intersect {} {} {}", name, obj1, obj2));
+                    }
+                    _ => {
+                        lines.push(format!("point {} {} {}", name, point.position.x, point.position.y));
+                    }
+                }
+            }
+            ConstructionStep::ConstructLine { line, point1_id, point2_id } => {
+                let name = name_for(&line.id, &line.label, &mut names, &mut line_counter, "L");
+                let from = names.get(point1_id).cloned().unwrap_or_else(|| point1_id.clone());
+                let to = names.get(point2_id).cloned().unwrap_or_else(|| point2_id.clone());
+                lines.push(format!("line {} from {} to {}", name, from, to));
+            }
+            ConstructionStep::ConstructCircle { circle, center_id, radius_point_id } => {
+                let name = name_for(&circle.id, &circle.label, &mut names, &mut circle_counter, "c");
+                let center = names.get(center_id).cloned().unwrap_or_else(|| center_id.clone());
+                let through = names.get(radius_point_id).cloned().unwrap_or_else(|| radius_point_id.clone());
+                lines.push(format!("circle {} center {} through {}", name, center, through));
+            }
+            ConstructionStep::FindIntersections { obj1_id, obj2_id } => {
+                let obj1 = names.get(obj1_id).cloned().unwrap_or_else(|| obj1_id.clone());
+                let obj2 = names.get(obj2_id).cloned().unwrap_or_else(|| obj2_id.clone());
+                point_counter += 1;
+                let name = format!("P{}", point_counter);
+                lines.push(format!("intersect {} {} {}", name, obj1, obj2));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_point_statement() {
+        let statements = parse_script("point A 0 0").unwrap();
+        assert_eq!(statements.len(), 1);
+        assert_eq!(
+            statements[0].statement,
+            Statement::Point {
+                name: "A".to_string(),
+                x: 0.0,
+                y: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_line_number() {
+        let err = parse_script("point A 0 0\nbogus statement").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_run_euclid_i_1() {
+        let src = "point A 0 0\n\
+                    point B 1 0\n\
+                    circle c1 center A through B\n\
+                    circle c2 center B through A\n\
+                    intersect P c1 c2\n\
+                    line AB from A to B";
+
+        let statements = parse_script(src).unwrap();
+        let mut space = ConstructionSpace::new();
+        let ids = run(&mut space, &statements).unwrap();
+
+        assert_eq!(ids.len(), 6);
+        assert_eq!(space.point_count(), 4); // A, B, and the two circle-circle intersections
+        assert_eq!(space.circle_count(), 2);
+        assert_eq!(space.line_count(), 1);
+    }
+
+    #[test]
+    fn test_run_undefined_name_reports_line() {
+        let statements = parse_script("line L from A to B").unwrap();
+        let mut space = ConstructionSpace::new();
+        let err = run(&mut space, &statements).unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+
+    #[test]
+    fn test_dump_script_round_trip() {
+        let mut space = ConstructionSpace::new();
+        let statements = parse_script("point A 0 0\npoint B 1 0\nline AB from A to B").unwrap();
+        run(&mut space, &statements).unwrap();
+
+        let dumped = dump_script(&space);
+        assert!(dumped.contains("point A 0 0"));
+        assert!(dumped.contains("line AB from A to B"));
+    }
+
+    #[test]
+    fn test_dump_script_emits_intersect_for_an_intersection_derived_point() {
+        // Two crossing lines meet at exactly one point, so the round trip
+        // below isn't complicated by a second, unnamed intersection point
+        let src = "point A 0 0\n\
+                    point B 2 2\n\
+                    point C 0 2\n\
+                    point D 2 0\n\
+                    line AB from A to B\n\
+                    line CD from C to D\n\
+                    intersect P AB CD";
+        let statements = parse_script(src).unwrap();
+        let mut space = ConstructionSpace::new();
+        run(&mut space, &statements).unwrap();
+
+        let dumped = dump_script(&space);
+        assert!(
+            dumped.contains("intersect P AB CD"),
+            "expected an `intersect` statement, got:\n{}",
+            dumped
+        );
+        assert!(!dumped.lines().any(|line| line.starts_with("point P ")));
+
+        // The dumped script should still re-run and rebuild an equivalent space
+        let redumped_statements = parse_script(&dumped).unwrap();
+        let mut rebuilt = ConstructionSpace::new();
+        run(&mut rebuilt, &redumped_statements).unwrap();
+        assert_eq!(rebuilt.point_count(), space.point_count());
+    }
+}
@@ -0,0 +1,271 @@
+//! Interoperable serialization for construction spaces: WKT and GeoJSON
+//!
+//! WKT already has its own parser/writer in `crate::wkt`; this module adds
+//! the GeoJSON half and re-exports the WKT entry points alongside it so
+//! callers have one place to reach for either format when talking to the
+//! wider GIS ecosystem (QGIS, `geo`, `geojson`) instead of the bespoke JSON
+//! the server binary speaks internally.
+
+use serde_json::{json, Value};
+
+use crate::construction::ConstructionSpace;
+use crate::geometry::Point;
+
+pub use crate::wkt::{export_construction as to_wkt, import_construction as from_wkt};
+
+/// Export a construction space as a GeoJSON `FeatureCollection`. Points
+/// become `Point` features, lines become `LineString` features between
+/// their two defining points, and circles become a `Point` feature at the
+/// center carrying its radius in `properties` — GIS consumers generally
+/// expect a circle as data, not a densified polygon.
+pub fn to_geojson(space: &ConstructionSpace) -> Value {
+    let mut features = Vec::new();
+
+    for point in space.points() {
+        features.push(json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "Point",
+                "coordinates": [point.position.x, point.position.y]
+            },
+            "properties": {
+                "kind": "point",
+                "id": point.id,
+                "label": point.label
+            }
+        }));
+    }
+
+    for line in space.lines() {
+        if let (Some(p1), Some(p2)) = (
+            space.get_point(&line.point1_id),
+            space.get_point(&line.point2_id),
+        ) {
+            features.push(json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": [
+                        [p1.position.x, p1.position.y],
+                        [p2.position.x, p2.position.y]
+                    ]
+                },
+                "properties": {
+                    "kind": "line",
+                    "id": line.id,
+                    "label": line.label
+                }
+            }));
+        }
+    }
+
+    for circle in space.circles() {
+        if let (Some(center), Some(radius_point)) = (
+            space.get_point(&circle.center_id),
+            space.get_point(&circle.radius_point_id),
+        ) {
+            let radius = center.distance_to(radius_point);
+            features.push(json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [center.position.x, center.position.y]
+                },
+                "properties": {
+                    "kind": "circle",
+                    "id": circle.id,
+                    "label": circle.label,
+                    "radius": radius
+                }
+            }));
+        }
+    }
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features
+    })
+}
+
+/// Import a GeoJSON `FeatureCollection`, rebuilding each feature as a fresh
+/// `Point`/`Line`/`Circle` with new UUIDs and dependency links, and
+/// returning the IDs of every element created, in order.
+pub fn from_geojson(space: &mut ConstructionSpace, geojson: &Value) -> Result<Vec<String>, String> {
+    let features = geojson
+        .get("features")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "expected a GeoJSON FeatureCollection with a \"features\" array".to_string())?;
+
+    let mut ids = Vec::new();
+    for feature in features {
+        import_feature(space, feature, &mut ids)?;
+    }
+
+    Ok(ids)
+}
+
+fn import_feature(space: &mut ConstructionSpace, feature: &Value, ids: &mut Vec<String>) -> Result<(), String> {
+    let geometry = feature
+        .get("geometry")
+        .ok_or_else(|| "feature missing \"geometry\"".to_string())?;
+    let geometry_type = geometry
+        .get("type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "geometry missing \"type\"".to_string())?;
+    let kind = feature
+        .get("properties")
+        .and_then(|p| p.get("kind"))
+        .and_then(|v| v.as_str());
+
+    match (geometry_type, kind) {
+        ("Point", Some("circle")) => {
+            let (x, y) = coordinate(geometry)?;
+            let radius = feature
+                .get("properties")
+                .and_then(|p| p.get("radius"))
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| "circle feature missing properties.radius".to_string())?;
+
+            let center_id = space.add_point(Point::new(x, y, None));
+            let radius_point_id = space.add_point(Point::new(x + radius, y, None));
+            ids.push(center_id.clone());
+            ids.push(radius_point_id.clone());
+
+            let circle_id = space
+                .construct_circle(&center_id, &radius_point_id, None)
+                .map_err(|e| e.to_string())?;
+            ids.push(circle_id);
+        }
+        ("Point", _) => {
+            let (x, y) = coordinate(geometry)?;
+            ids.push(space.add_point(Point::new(x, y, None)));
+        }
+        ("LineString", _) => {
+            let coords = coordinate_list(geometry)?;
+            let mut point_ids = Vec::with_capacity(coords.len());
+            for (x, y) in coords {
+                let id = space.add_point(Point::new(x, y, None));
+                ids.push(id.clone());
+                point_ids.push(id);
+            }
+            for pair in point_ids.windows(2) {
+                let line_id = space
+                    .construct_line(&pair[0], &pair[1], None)
+                    .map_err(|e| e.to_string())?;
+                ids.push(line_id);
+            }
+        }
+        (other, _) => return Err(format!("unsupported GeoJSON geometry type: {}", other)),
+    }
+
+    Ok(())
+}
+
+fn coordinate(geometry: &Value) -> Result<(f64, f64), String> {
+    let coords = geometry
+        .get("coordinates")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "geometry missing \"coordinates\"".to_string())?;
+    let x = coords
+        .first()
+        .and_then(|v| v.as_f64())
+        .ok_or_else(|| "coordinate missing x".to_string())?;
+    let y = coords
+        .get(1)
+        .and_then(|v| v.as_f64())
+        .ok_or_else(|| "coordinate missing y".to_string())?;
+    Ok((x, y))
+}
+
+fn coordinate_list(geometry: &Value) -> Result<Vec<(f64, f64)>, String> {
+    let coords = geometry
+        .get("coordinates")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "geometry missing \"coordinates\"".to_string())?;
+
+    coords
+        .iter()
+        .map(|c| {
+            let pair = c
+                .as_array()
+                .ok_or_else(|| "coordinate is not an array".to_string())?;
+            let x = pair
+                .first()
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| "coordinate missing x".to_string())?;
+            let y = pair
+                .get(1)
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| "coordinate missing y".to_string())?;
+            Ok((x, y))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_geojson_point() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(1.0, 2.0, None));
+
+        let geojson = to_geojson(&space);
+        assert_eq!(geojson["type"], "FeatureCollection");
+        assert_eq!(geojson["features"][0]["geometry"]["type"], "Point");
+        assert_eq!(geojson["features"][0]["geometry"]["coordinates"][0], 1.0);
+    }
+
+    #[test]
+    fn test_import_geojson_point() {
+        let mut space = ConstructionSpace::new();
+        let geojson = json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": {"type": "Point", "coordinates": [3.0, 4.0]},
+                "properties": {"kind": "point"}
+            }]
+        });
+
+        let ids = from_geojson(&mut space, &geojson).unwrap();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(space.point_count(), 1);
+    }
+
+    #[test]
+    fn test_import_geojson_linestring() {
+        let mut space = ConstructionSpace::new();
+        let geojson = json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": {"type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]]},
+                "properties": {"kind": "line"}
+            }]
+        });
+
+        let ids = from_geojson(&mut space, &geojson).unwrap();
+        assert_eq!(ids.len(), 3); // 2 points + 1 line
+        assert_eq!(space.point_count(), 2);
+        assert_eq!(space.line_count(), 1);
+    }
+
+    #[test]
+    fn test_round_trip_circle() {
+        let mut space = ConstructionSpace::new();
+        let center_id = space.add_point(Point::new(0.0, 0.0, None));
+        let radius_point_id = space.add_point(Point::new(5.0, 0.0, None));
+        space
+            .construct_circle(&center_id, &radius_point_id, None)
+            .unwrap();
+
+        let geojson = to_geojson(&space);
+        let mut new_space = ConstructionSpace::new();
+        let ids = from_geojson(&mut new_space, &geojson).unwrap();
+
+        assert!(!ids.is_empty());
+        assert_eq!(new_space.circle_count(), 1);
+    }
+}
@@ -0,0 +1,394 @@
+//! A uniform-grid spatial index over a construction space
+//!
+//! Construction spaces are usually small enough that point-location and
+//! intersection-candidate queries could just scan every object, but a
+//! uniform grid keeps each query to a handful of buckets instead, which
+//! starts to matter once a traced construction (or a triangulation/hull
+//! built from one) has hundreds of points. Points are bucketed by the grid
+//! cell their coordinates fall in; lines and circles are bucketed by every
+//! cell their bounding box overlaps, so overlap-based queries don't miss an
+//! object whose extent straddles a cell boundary.
+
+use std::collections::HashMap;
+
+use nalgebra::Point2;
+
+use crate::construction::ConstructionSpace;
+use crate::geometry::Point;
+
+type Cell = (i64, i64);
+
+/// An axis-aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Point2<f64>,
+    pub max: Point2<f64>,
+}
+
+impl Aabb {
+    /// The smallest `Aabb` enclosing every point in `points`. Panics if
+    /// `points` is empty, as there is no meaningful bounding box for it.
+    pub fn of_points(points: &[Point2<f64>]) -> Self {
+        let first = points[0];
+        let mut aabb = Aabb { min: first, max: first };
+        for &p in &points[1..] {
+            aabb.min.x = aabb.min.x.min(p.x);
+            aabb.min.y = aabb.min.y.min(p.y);
+            aabb.max.x = aabb.max.x.max(p.x);
+            aabb.max.y = aabb.max.y.max(p.y);
+        }
+        aabb
+    }
+
+    /// Whether this box and `other` overlap, treating touching edges as overlap
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// Whether `p` falls within this box (inclusive of the boundary)
+    pub fn contains(&self, p: Point2<f64>) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+}
+
+/// A uniform grid over a construction space's points, lines, and circles
+pub struct SpatialIndex {
+    cell_size: f64,
+    point_cells: HashMap<Cell, Vec<String>>,
+    point_cell_of: HashMap<String, Cell>,
+    object_cells: HashMap<Cell, Vec<String>>,
+    object_bounds: HashMap<String, Aabb>,
+}
+
+impl SpatialIndex {
+    /// Build an index over every point/line/circle currently in `space`,
+    /// using `cell_size` as the grid resolution. A cell size on the order of
+    /// the construction's typical point spacing keeps bucket occupancy low.
+    pub fn build(space: &ConstructionSpace, cell_size: f64) -> Self {
+        let mut index = Self {
+            cell_size,
+            point_cells: HashMap::new(),
+            point_cell_of: HashMap::new(),
+            object_cells: HashMap::new(),
+            object_bounds: HashMap::new(),
+        };
+
+        for point in space.points() {
+            index.insert_point(&point.id, point.position);
+        }
+
+        for line in space.lines() {
+            if let (Some(p1), Some(p2)) = (space.get_point(&line.point1_id), space.get_point(&line.point2_id)) {
+                index.insert_object(&line.id, Aabb::of_points(&[p1.position, p2.position]));
+            }
+        }
+
+        for circle in space.circles() {
+            if let (Some(center), Some(radius_point)) =
+                (space.get_point(&circle.center_id), space.get_point(&circle.radius_point_id))
+            {
+                let radius = center.distance_to(radius_point);
+                let min = Point2::new(center.position.x - radius, center.position.y - radius);
+                let max = Point2::new(center.position.x + radius, center.position.y + radius);
+                index.insert_object(&circle.id, Aabb { min, max });
+            }
+        }
+
+        index
+    }
+
+    fn cell_for(&self, p: Point2<f64>) -> Cell {
+        ((p.x / self.cell_size).floor() as i64, (p.y / self.cell_size).floor() as i64)
+    }
+
+    fn cells_for_aabb(&self, aabb: &Aabb) -> Vec<Cell> {
+        let (min_cx, min_cy) = self.cell_for(aabb.min);
+        let (max_cx, max_cy) = self.cell_for(aabb.max);
+        let mut cells = Vec::new();
+        for cx in min_cx..=max_cx {
+            for cy in min_cy..=max_cy {
+                cells.push((cx, cy));
+            }
+        }
+        cells
+    }
+
+    /// Insert or re-insert a point, keyed by `point_id`. Re-inserting an
+    /// already-indexed ID first removes its stale cell membership, so moving
+    /// a point is just `insert_point` with its new position.
+    pub fn insert_point(&mut self, point_id: &str, position: Point2<f64>) {
+        self.remove_point(point_id);
+        let cell = self.cell_for(position);
+        self.point_cells.entry(cell).or_default().push(point_id.to_string());
+        self.point_cell_of.insert(point_id.to_string(), cell);
+    }
+
+    /// Drop a point from the index. A no-op if `point_id` isn't indexed.
+    pub fn remove_point(&mut self, point_id: &str) {
+        if let Some(cell) = self.point_cell_of.remove(point_id) {
+            if let Some(ids) = self.point_cells.get_mut(&cell) {
+                ids.retain(|id| id != point_id);
+                if ids.is_empty() {
+                    self.point_cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Insert or re-insert a line/circle, keyed by `id`, with its bounding
+    /// box `aabb`. Re-inserting an already-indexed ID first removes its
+    /// stale cell membership.
+    pub fn insert_object(&mut self, id: &str, aabb: Aabb) {
+        self.remove_object(id);
+        for cell in self.cells_for_aabb(&aabb) {
+            self.object_cells.entry(cell).or_default().push(id.to_string());
+        }
+        self.object_bounds.insert(id.to_string(), aabb);
+    }
+
+    /// Drop a line/circle from the index. A no-op if `id` isn't indexed.
+    pub fn remove_object(&mut self, id: &str) {
+        if let Some(aabb) = self.object_bounds.remove(id) {
+            for cell in self.cells_for_aabb(&aabb) {
+                if let Some(ids) = self.object_cells.get_mut(&cell) {
+                    ids.retain(|existing| existing != id);
+                    if ids.is_empty() {
+                        self.object_cells.remove(&cell);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find the point nearest to `query` and within `tolerance` of it,
+    /// searching outward ring-by-ring from its cell until a candidate is
+    /// found and no closer ring remains. Returns `None` if the index has no
+    /// point, or none within `tolerance` of `query`.
+    pub fn nearest_point<'a>(&self, space: &'a ConstructionSpace, query: Point2<f64>, tolerance: f64) -> Option<&'a Point> {
+        let (cx, cy) = self.cell_for(query);
+        let mut best: Option<(&'a Point, f64)> = None;
+
+        for radius in 0..=max_grid_radius(&self.point_cells, cx, cy) {
+            // Once the ring's nearest possible point is already further than
+            // `tolerance`, nothing left to search can beat it
+            if (radius as f64 - 1.0).max(0.0) * self.cell_size > tolerance {
+                break;
+            }
+
+            for cx_off in -radius..=radius {
+                for cy_off in -radius..=radius {
+                    // Only the ring's perimeter is new at this radius; interior cells were already visited
+                    if radius > 0 && cx_off.abs() != radius && cy_off.abs() != radius {
+                        continue;
+                    }
+                    let cell = (cx + cx_off, cy + cy_off);
+                    if let Some(ids) = self.point_cells.get(&cell) {
+                        for id in ids {
+                            if let Some(point) = space.get_point(id) {
+                                let dist = crate::ops::distance(point.position, query);
+                                if dist > tolerance {
+                                    continue;
+                                }
+                                let is_closer = match best {
+                                    Some((_, best_dist)) => dist < best_dist,
+                                    None => true,
+                                };
+                                if is_closer {
+                                    best = Some((point, dist));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Once something is found, one extra ring guards against a
+            // closer point sitting just across the current ring's boundary
+            if let Some((_, best_dist)) = best {
+                if best_dist <= (radius as f64) * self.cell_size {
+                    break;
+                }
+            }
+        }
+
+        best.map(|(point, _)| point)
+    }
+
+    /// IDs of every indexed line/circle whose bounding box overlaps `aabb`,
+    /// excluding `exclude_id` itself (so a line/circle can query its own
+    /// candidates without matching itself)
+    pub fn candidates_intersecting(&self, aabb: &Aabb, exclude_id: Option<&str>) -> Vec<String> {
+        let mut seen = std::collections::HashSet::new();
+        self.cells_for_aabb(aabb)
+            .into_iter()
+            .filter_map(|cell| self.object_cells.get(&cell))
+            .flatten()
+            .filter(|id| exclude_id != Some(id.as_str()))
+            .filter(|id| self.object_bounds.get(id.as_str()).map_or(false, |bounds| bounds.intersects(aabb)))
+            .filter(|id| seen.insert((*id).clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// IDs of every indexed point whose cell overlaps `aabb`; a point just
+    /// outside `aabb` but in one of those cells is included too
+    pub fn points_in_range(&self, aabb: &Aabb) -> Vec<&str> {
+        self.cells_for_aabb(aabb)
+            .into_iter()
+            .filter_map(|cell| self.point_cells.get(&cell))
+            .flatten()
+            .map(|id| id.as_str())
+            .collect()
+    }
+
+    /// Candidate (line/circle, line/circle) ID pairs whose bounding boxes
+    /// overlap, for broad-phase intersection testing before running the
+    /// exact geometric predicates on each pair
+    pub fn candidate_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+
+        for ids in self.object_cells.values() {
+            for i in 0..ids.len() {
+                for j in (i + 1)..ids.len() {
+                    let (a, b) = if ids[i] < ids[j] { (&ids[i], &ids[j]) } else { (&ids[j], &ids[i]) };
+                    if self.object_bounds[a].intersects(&self.object_bounds[b]) && seen.insert((a.clone(), b.clone())) {
+                        pairs.push((a.clone(), b.clone()));
+                    }
+                }
+            }
+        }
+
+        pairs
+    }
+}
+
+/// How many rings the nearest-point search must walk outward from the query
+/// cell `(cx, cy)` to be guaranteed to reach every occupied cell - the
+/// Chebyshev distance from `(cx, cy)` to the farthest occupied cell, plus
+/// one ring of margin. Must be measured from the query cell itself, not
+/// from the grid's origin, or a query far from `(0, 0)` stops searching
+/// long before it reaches cells near the origin.
+fn max_grid_radius(cells: &HashMap<Cell, Vec<String>>, cx: i64, cy: i64) -> i64 {
+    cells
+        .keys()
+        .map(|&(x, y)| (x - cx).unsigned_abs().max((y - cy).unsigned_abs()))
+        .max()
+        .unwrap_or(0) as i64
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    #[test]
+    fn test_aabb_intersects() {
+        let a = Aabb { min: Point2::new(0.0, 0.0), max: Point2::new(1.0, 1.0) };
+        let b = Aabb { min: Point2::new(0.5, 0.5), max: Point2::new(2.0, 2.0) };
+        let c = Aabb { min: Point2::new(5.0, 5.0), max: Point2::new(6.0, 6.0) };
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn test_nearest_point() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        let far_id = space.add_point(Point::new(10.0, 10.0, None));
+        let near_id = space.add_point(Point::new(1.0, 1.0, None));
+
+        let index = SpatialIndex::build(&space, 1.0);
+        let nearest = index.nearest_point(&space, Point2::new(0.9, 0.9), 5.0).unwrap();
+        assert_eq!(nearest.id, near_id);
+        assert_ne!(nearest.id, far_id);
+    }
+
+    #[test]
+    fn test_nearest_point_respects_tolerance() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(10.0, 10.0, None));
+
+        let index = SpatialIndex::build(&space, 1.0);
+        assert!(index.nearest_point(&space, Point2::new(0.0, 0.0), 1.0).is_none());
+        assert!(index.nearest_point(&space, Point2::new(0.0, 0.0), 100.0).is_some());
+    }
+
+    #[test]
+    fn test_nearest_point_reaches_far_cells_relative_to_the_query_not_the_origin() {
+        let mut space = ConstructionSpace::new();
+        // A single point near the grid's origin...
+        let far_id = space.add_point(Point::new(5.0, 5.0, None));
+
+        let index = SpatialIndex::build(&space, 1.0);
+        // ...queried from a cell far from (0, 0), with a tolerance wide
+        // enough to reach it. The search must walk outward from the query's
+        // own cell, not be capped by the farthest cell's distance from the origin.
+        let nearest = index.nearest_point(&space, Point2::new(1000.0, 1000.0), 1410.0).unwrap();
+        assert_eq!(nearest.id, far_id);
+    }
+
+    #[test]
+    fn test_insert_and_remove_point_update_the_index() {
+        let mut space = ConstructionSpace::new();
+        let id = space.add_point(Point::new(0.0, 0.0, None));
+
+        let mut index = SpatialIndex::build(&space, 1.0);
+        assert!(index.nearest_point(&space, Point2::new(0.0, 0.0), 0.5).is_some());
+
+        index.remove_point(&id);
+        assert!(index.nearest_point(&space, Point2::new(0.0, 0.0), 0.5).is_none());
+
+        space.set_point_position(&id, 20.0, 20.0).unwrap();
+        index.insert_point(&id, Point2::new(20.0, 20.0));
+        assert!(index.nearest_point(&space, Point2::new(0.0, 0.0), 0.5).is_none());
+        assert!(index.nearest_point(&space, Point2::new(20.0, 20.0), 0.5).is_some());
+    }
+
+    #[test]
+    fn test_candidates_intersecting_excludes_self_and_misses() {
+        let mut space = ConstructionSpace::new();
+        let a1 = space.add_point(Point::new(0.0, 0.0, None));
+        let a2 = space.add_point(Point::new(1.0, 0.0, None));
+        let b1 = space.add_point(Point::new(100.0, 100.0, None));
+        let b2 = space.add_point(Point::new(101.0, 100.0, None));
+        let line1 = space.construct_line(&a1, &a2, None).unwrap();
+        let line2 = space.construct_line(&b1, &b2, None).unwrap();
+
+        let index = SpatialIndex::build(&space, 5.0);
+        let line1_bounds = Aabb::of_points(&[space.get_point(&a1).unwrap().position, space.get_point(&a2).unwrap().position]);
+
+        let candidates = index.candidates_intersecting(&line1_bounds, Some(&line1));
+        assert!(!candidates.contains(&line1));
+        assert!(!candidates.contains(&line2));
+    }
+
+    #[test]
+    fn test_points_in_range() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        space.add_point(Point::new(50.0, 50.0, None));
+
+        let index = SpatialIndex::build(&space, 2.0);
+        let aabb = Aabb { min: Point2::new(-1.0, -1.0), max: Point2::new(1.0, 1.0) };
+        assert_eq!(index.points_in_range(&aabb).len(), 1);
+    }
+
+    #[test]
+    fn test_candidate_pairs_only_overlapping() {
+        let mut space = ConstructionSpace::new();
+        let a1 = space.add_point(Point::new(0.0, 0.0, None));
+        let a2 = space.add_point(Point::new(1.0, 0.0, None));
+        let b1 = space.add_point(Point::new(100.0, 100.0, None));
+        let b2 = space.add_point(Point::new(101.0, 100.0, None));
+        space.construct_line(&a1, &a2, None).unwrap();
+        space.construct_line(&b1, &b2, None).unwrap();
+
+        let index = SpatialIndex::build(&space, 5.0);
+        assert!(index.candidate_pairs().is_empty());
+    }
+}
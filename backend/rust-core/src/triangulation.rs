@@ -0,0 +1,375 @@
+//! Delaunay triangulation over a cloud of points
+//!
+//! Builds a Delaunay triangulation via incremental insertion: each new point
+//! splits the triangle that contains it into three, and the resulting outer
+//! edges are legalized by recursively flipping any edge whose opposite
+//! vertex falls inside the current triangle's circumcircle.
+
+use crate::geometry::{Triangle, EPSILON};
+use crate::collection::TriangleType;
+use nalgebra::Point2;
+use std::collections::HashMap;
+
+/// An undirected edge between two point indices, stored in canonical
+/// (smaller, larger) order so it can be used as a hash map key regardless of
+/// which triangle is looking it up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge(pub usize, pub usize);
+
+impl Edge {
+    fn new(a: usize, b: usize) -> Self {
+        if a < b {
+            Edge(a, b)
+        } else {
+            Edge(b, a)
+        }
+    }
+}
+
+/// The result of triangulating a point set: the triangles themselves plus an
+/// edge-to-triangle adjacency map for walking neighbors.
+#[derive(Debug, Clone)]
+pub struct Triangulation {
+    pub triangles: Vec<Triangle>,
+    /// Maps each edge to the (at most two) triangle indices sharing it
+    pub adjacency: HashMap<Edge, (Option<usize>, Option<usize>)>,
+}
+
+/// Internal triangle representation as indices into the working point buffer
+type TriVerts = [usize; 3];
+
+struct Builder {
+    points: Vec<Point2<f64>>,
+    triangles: Vec<Option<TriVerts>>,
+    edge_map: HashMap<Edge, (Option<usize>, Option<usize>)>,
+}
+
+impl Builder {
+    fn add_triangle(&mut self, verts: TriVerts) -> usize {
+        let id = self.triangles.len();
+        self.triangles.push(Some(verts));
+        for edge in triangle_edges(verts) {
+            let entry = self.edge_map.entry(edge).or_insert((None, None));
+            if entry.0.is_none() {
+                entry.0 = Some(id);
+            } else {
+                entry.1 = Some(id);
+            }
+        }
+        id
+    }
+
+    fn remove_triangle(&mut self, id: usize) {
+        if let Some(verts) = self.triangles[id].take() {
+            for edge in triangle_edges(verts) {
+                if let Some(entry) = self.edge_map.get_mut(&edge) {
+                    if entry.0 == Some(id) {
+                        entry.0 = entry.1.take();
+                    } else if entry.1 == Some(id) {
+                        entry.1 = None;
+                    }
+                    if entry.0.is_none() && entry.1.is_none() {
+                        self.edge_map.remove(&edge);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find the triangle containing `p`, scanning all live triangles
+    fn locate_containing(&self, p: Point2<f64>) -> Option<usize> {
+        self.triangles.iter().enumerate().find_map(|(id, verts)| {
+            let verts = (*verts)?;
+            let a = self.points[verts[0]];
+            let b = self.points[verts[1]];
+            let c = self.points[verts[2]];
+            point_in_triangle(p, a, b, c).then_some(id)
+        })
+    }
+
+    /// Split the triangle `tri_id` into three by inserting `p_idx`, returning
+    /// the new triangle ids together with the outer edge each one carries
+    /// over from the split triangle.
+    fn split_triangle(&mut self, tri_id: usize, p_idx: usize) -> Vec<(usize, Edge)> {
+        let verts = self.triangles[tri_id].unwrap();
+        self.remove_triangle(tri_id);
+
+        let mut created = Vec::with_capacity(3);
+        for i in 0..3 {
+            let a = verts[i];
+            let b = verts[(i + 1) % 3];
+            let new_id = self.add_triangle([a, b, p_idx]);
+            created.push((new_id, Edge::new(a, b)));
+        }
+        created
+    }
+
+    /// Recursively restore the Delaunay property across `edge`, whose
+    /// current triangle has apex `p_idx`.
+    fn legalize(&mut self, edge: Edge, p_idx: usize) {
+        let Some(&(t0, t1)) = self.edge_map.get(&edge) else {
+            return;
+        };
+
+        // Find the triangle on the far side of `edge` from `p_idx`
+        let (near, far) = match (t0, t1) {
+            (Some(a), Some(b)) => {
+                let verts_a = self.triangles[a];
+                if verts_a.map_or(false, |v| v.contains(&p_idx)) {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            }
+            _ => return, // boundary edge, nothing to flip against
+        };
+
+        let Some(far_verts) = self.triangles[far] else {
+            return;
+        };
+        let opposite = *far_verts.iter().find(|v| **v != edge.0 && **v != edge.1).unwrap();
+
+        let a = self.points[edge.0];
+        let b = self.points[edge.1];
+        let p = self.points[p_idx];
+        let c = self.points[opposite];
+
+        if !in_circumcircle(a, b, c, p) {
+            return;
+        }
+
+        // Flip: replace (p, a, b) and (a, b, c) with (p, a, c) and (p, b, c)
+        self.remove_triangle(near);
+        self.remove_triangle(far);
+        self.add_triangle([p_idx, edge.0, opposite]);
+        self.add_triangle([p_idx, edge.1, opposite]);
+
+        self.legalize(Edge::new(edge.0, opposite), p_idx);
+        self.legalize(Edge::new(edge.1, opposite), p_idx);
+    }
+}
+
+fn triangle_edges(verts: TriVerts) -> [Edge; 3] {
+    [
+        Edge::new(verts[0], verts[1]),
+        Edge::new(verts[1], verts[2]),
+        Edge::new(verts[2], verts[0]),
+    ]
+}
+
+fn point_in_triangle(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> bool {
+    let d1 = cross(p, a, b);
+    let d2 = cross(p, b, c);
+    let d3 = cross(p, c, a);
+
+    let has_neg = d1 < -EPSILON || d2 < -EPSILON || d3 < -EPSILON;
+    let has_pos = d1 > EPSILON || d2 > EPSILON || d3 > EPSILON;
+
+    !(has_neg && has_pos)
+}
+
+fn cross(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>) -> f64 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// In-circle determinant test: is `d` strictly inside the circumcircle of
+/// `a`, `b`, `c`? The determinant's sign is corrected for the winding of
+/// `a`, `b`, `c`, so they may be given in either order.
+fn in_circumcircle(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>) -> bool {
+    let ax = a.x - d.x;
+    let ay = a.y - d.y;
+    let bx = b.x - d.x;
+    let by = b.y - d.y;
+    let cx = c.x - d.x;
+    let cy = c.y - d.y;
+
+    let det = (ax * ax + ay * ay) * (bx * cy - cx * by)
+        - (bx * bx + by * by) * (ax * cy - cx * ay)
+        + (cx * cx + cy * cy) * (ax * by - bx * ay);
+
+    // The determinant is positive for "inside" only when a, b, c turn
+    // counter-clockwise; callers pass them in edge-index order
+    let orientation = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+    if orientation < 0.0 {
+        -det > EPSILON
+    } else {
+        det > EPSILON
+    }
+}
+
+/// Compute the Delaunay triangulation of `points`
+pub fn delaunay(points: &[Point2<f64>]) -> Triangulation {
+    if points.len() < 3 {
+        return Triangulation {
+            triangles: Vec::new(),
+            adjacency: HashMap::new(),
+        };
+    }
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for p in points {
+        min_x = min_x.min(p.x);
+        min_y = min_y.min(p.y);
+        max_x = max_x.max(p.x);
+        max_y = max_y.max(p.y);
+    }
+    let dx = (max_x - min_x).max(1.0);
+    let dy = (max_y - min_y).max(1.0);
+    let delta_max = dx.max(dy) * 20.0;
+    let mid_x = (min_x + max_x) / 2.0;
+    let mid_y = (min_y + max_y) / 2.0;
+
+    let mut working_points = points.to_vec();
+    let super_a = working_points.len();
+    working_points.push(Point2::new(mid_x - 2.0 * delta_max, mid_y - delta_max));
+    let super_b = working_points.len();
+    working_points.push(Point2::new(mid_x, mid_y + 2.0 * delta_max));
+    let super_c = working_points.len();
+    working_points.push(Point2::new(mid_x + 2.0 * delta_max, mid_y - delta_max));
+
+    let mut builder = Builder {
+        points: working_points,
+        triangles: Vec::new(),
+        edge_map: HashMap::new(),
+    };
+    builder.add_triangle([super_a, super_b, super_c]);
+
+    for p_idx in 0..points.len() {
+        let p = builder.points[p_idx];
+        let Some(containing) = builder.locate_containing(p) else {
+            continue; // degenerate/duplicate point, skip rather than loop forever
+        };
+
+        let outer_edges = builder.split_triangle(containing, p_idx);
+        for (_, edge) in outer_edges {
+            builder.legalize(edge, p_idx);
+        }
+    }
+
+    let triangles: Vec<Triangle> = builder
+        .triangles
+        .iter()
+        .filter_map(|verts| {
+            let verts = (*verts)?;
+            if verts.contains(&super_a) || verts.contains(&super_b) || verts.contains(&super_c) {
+                return None;
+            }
+            Some(Triangle::new(
+                builder.points[verts[0]],
+                builder.points[verts[1]],
+                builder.points[verts[2]],
+            ))
+        })
+        .collect();
+
+    // Re-key the adjacency map to only the surviving triangles, dropping any
+    // edge that touched a super-triangle vertex.
+    let mut adjacency = HashMap::new();
+    let mut keep_id = HashMap::new();
+    for (id, verts) in builder.triangles.iter().enumerate() {
+        let Some(verts) = verts else { continue };
+        if verts.contains(&super_a) || verts.contains(&super_b) || verts.contains(&super_c) {
+            continue;
+        }
+        keep_id.insert(id, keep_id.len());
+    }
+    for (edge, (t0, t1)) in builder.edge_map.iter() {
+        if edge.0 == super_a || edge.0 == super_b || edge.0 == super_c {
+            continue;
+        }
+        if edge.1 == super_a || edge.1 == super_b || edge.1 == super_c {
+            continue;
+        }
+        let new_t0 = t0.and_then(|t| keep_id.get(&t).copied());
+        let new_t1 = t1.and_then(|t| keep_id.get(&t).copied());
+        if new_t0.is_some() || new_t1.is_some() {
+            adjacency.insert(*edge, (new_t0, new_t1));
+        }
+    }
+
+    Triangulation {
+        triangles,
+        adjacency,
+    }
+}
+
+/// Classify a triangle by its angles (and side lengths) for the collection system
+pub fn classify_triangle_type(triangle: &Triangle) -> TriangleType {
+    let ab = nalgebra::distance(&triangle.a, &triangle.b);
+    let bc = nalgebra::distance(&triangle.b, &triangle.c);
+    let ca = nalgebra::distance(&triangle.c, &triangle.a);
+
+    if triangle.is_right_angled(EPSILON) {
+        return TriangleType::Right;
+    }
+
+    let equal = |x: f64, y: f64| (x - y).abs() < EPSILON;
+    if equal(ab, bc) && equal(bc, ca) {
+        return TriangleType::Equilateral;
+    }
+    if equal(ab, bc) || equal(bc, ca) || equal(ca, ab) {
+        return TriangleType::Isosceles;
+    }
+
+    let sides = [ab, bc, ca];
+    let longest = sides.iter().cloned().fold(f64::MIN, f64::max);
+    let sum_of_squares: f64 = sides
+        .iter()
+        .filter(|&&s| s != longest)
+        .map(|s| s * s)
+        .sum();
+
+    if longest * longest > sum_of_squares + EPSILON {
+        TriangleType::Obtuse
+    } else {
+        TriangleType::Acute
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delaunay_single_triangle() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let result = delaunay(&points);
+        assert_eq!(result.triangles.len(), 1);
+    }
+
+    #[test]
+    fn test_delaunay_square() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let result = delaunay(&points);
+        assert_eq!(result.triangles.len(), 2);
+    }
+
+    #[test]
+    fn test_classify_equilateral() {
+        let triangle = Triangle::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.5, 3.0_f64.sqrt() / 2.0),
+        );
+        assert_eq!(classify_triangle_type(&triangle), TriangleType::Equilateral);
+    }
+
+    #[test]
+    fn test_classify_right() {
+        let triangle = Triangle::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(0.0, 4.0),
+        );
+        assert_eq!(classify_triangle_type(&triangle), TriangleType::Right);
+    }
+}
@@ -94,12 +94,12 @@ pub mod angle_utils {
             return 0.0;
         }
         
-        (dot / lengths).acos()
+        crate::ops::acos(dot / lengths)
     }
 
     /// Calculate angle of vector from positive x-axis
     pub fn vector_angle(vector: Vector2<f64>) -> f64 {
-        vector.y.atan2(vector.x)
+        crate::ops::atan2(vector.y, vector.x)
     }
 
     /// Calculate angle from three points (angle at middle point)
@@ -245,7 +245,7 @@ pub mod validation {
 
     /// Validate that points are not coincident
     pub fn points_are_distinct(p1: &Point2<f64>, p2: &Point2<f64>) -> bool {
-        nalgebra::distance(p1, p2) > EPSILON
+        crate::ops::distance(*p1, *p2) > EPSILON
     }
 
     /// Validate that three points are not collinear
@@ -265,6 +265,118 @@ pub mod validation {
     }
 }
 
+/// A slab arena keyed by compact integer handles instead of hashed keys
+pub mod slab {
+    use serde::{Deserialize, Serialize};
+
+    /// A `Vec<Option<T>>` with free-list reuse of vacated slots, giving O(1)
+    /// `insert`/`get`/`remove` by `usize` handle instead of the allocation
+    /// and hashing cost of a string-keyed `HashMap`.
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    pub struct IndexSlab<T> {
+        slots: Vec<Option<T>>,
+        free: Vec<usize>,
+    }
+
+    impl<T> IndexSlab<T> {
+        /// Create a new empty slab
+        pub fn new() -> Self {
+            Self {
+                slots: Vec::new(),
+                free: Vec::new(),
+            }
+        }
+
+        /// Insert a value, returning the handle it was stored under
+        pub fn insert(&mut self, value: T) -> usize {
+            if let Some(handle) = self.free.pop() {
+                self.slots[handle] = Some(value);
+                handle
+            } else {
+                self.slots.push(Some(value));
+                self.slots.len() - 1
+            }
+        }
+
+        /// Get a reference to the value at `handle`, if it is still occupied
+        pub fn get(&self, handle: usize) -> Option<&T> {
+            self.slots.get(handle).and_then(|slot| slot.as_ref())
+        }
+
+        /// Get a mutable reference to the value at `handle`, if still occupied
+        pub fn get_mut(&mut self, handle: usize) -> Option<&mut T> {
+            self.slots.get_mut(handle).and_then(|slot| slot.as_mut())
+        }
+
+        /// Remove and return the value at `handle`, freeing the slot for reuse
+        pub fn remove(&mut self, handle: usize) -> Option<T> {
+            let value = self.slots.get_mut(handle)?.take();
+            if value.is_some() {
+                self.free.push(handle);
+            }
+            value
+        }
+
+        /// Number of occupied slots
+        pub fn len(&self) -> usize {
+            self.slots.len() - self.free.len()
+        }
+
+        /// Whether the slab has no occupied slots
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Iterate over occupied values in handle order
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            self.slots.iter().filter_map(|slot| slot.as_ref())
+        }
+
+        /// Drop every value and free slot, resetting the slab to empty
+        pub fn clear(&mut self) {
+            self.slots.clear();
+            self.free.clear();
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_insert_and_get() {
+            let mut slab = IndexSlab::new();
+            let handle = slab.insert("a");
+            assert_eq!(slab.get(handle), Some(&"a"));
+            assert_eq!(slab.len(), 1);
+        }
+
+        #[test]
+        fn test_remove_and_reuse() {
+            let mut slab = IndexSlab::new();
+            let h1 = slab.insert(1);
+            let h2 = slab.insert(2);
+            assert_eq!(slab.remove(h1), Some(1));
+            assert_eq!(slab.len(), 1);
+
+            let h3 = slab.insert(3);
+            assert_eq!(h3, h1); // reused the freed slot
+            assert_eq!(slab.get(h2), Some(&2));
+            assert_eq!(slab.get(h3), Some(&3));
+        }
+
+        #[test]
+        fn test_iter_skips_removed() {
+            let mut slab = IndexSlab::new();
+            let h1 = slab.insert(1);
+            slab.insert(2);
+            slab.remove(h1);
+            let remaining: Vec<_> = slab.iter().collect();
+            assert_eq!(remaining, vec![&2]);
+        }
+    }
+}
+
 /// String formatting utilities
 pub mod format_utils {
     /// Format a floating point number for display
@@ -0,0 +1,376 @@
+//! Well-Known Text (WKT) import/export for constructions
+//!
+//! A small recursive-descent parser and writer for the `POINT` /
+//! `LINESTRING` / `POLYGON` / `GEOMETRYCOLLECTION` subset of WKT, enough to
+//! export a construction space and read its geometries back without pulling
+//! in a full GIS dependency. Circles are written as a densified `POLYGON` and
+//! come back as points joined by lines, not as a `Circle`.
+
+use crate::construction::ConstructionSpace;
+use crate::geometry::Point;
+
+/// A parsed WKT geometry
+#[derive(Debug, Clone, PartialEq)]
+pub enum WktGeometry {
+    Point(f64, f64),
+    LineString(Vec<(f64, f64)>),
+    Polygon(Vec<(f64, f64)>),
+    GeometryCollection(Vec<WktGeometry>),
+}
+
+impl WktGeometry {
+    /// Parse a WKT string into a geometry tree
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let tokens = tokenize(input);
+        let mut pos = 0;
+        let geometry = parse_geometry(&tokens, &mut pos)?;
+        Ok(geometry)
+    }
+
+    /// Serialize this geometry back to WKT
+    pub fn to_wkt(&self) -> String {
+        match self {
+            WktGeometry::Point(x, y) => format!("POINT ({} {})", x, y),
+            WktGeometry::LineString(pts) => format!("LINESTRING ({})", format_coords(pts)),
+            WktGeometry::Polygon(pts) => format!("POLYGON (({}))", format_coords(pts)),
+            WktGeometry::GeometryCollection(geoms) => {
+                let parts: Vec<String> = geoms.iter().map(|g| g.to_wkt()).collect();
+                format!("GEOMETRYCOLLECTION ({})", parts.join(", "))
+            }
+        }
+    }
+}
+
+fn format_coords(pts: &[(f64, f64)]) -> String {
+    pts.iter()
+        .map(|(x, y)| format!("{} {}", x, y))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    LParen,
+    RParen,
+    Comma,
+    Number(f64),
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                chars.next();
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                chars.next();
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_alphabetic() {
+                        word.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Word(word.to_uppercase()));
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let mut num = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+                        num.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if let Ok(n) = num.parse::<f64>() {
+                    tokens.push(Token::Number(n));
+                }
+            }
+            _ => {
+                chars.next();
+            }
+        }
+    }
+
+    tokens
+}
+
+fn parse_geometry(tokens: &[Token], pos: &mut usize) -> Result<WktGeometry, String> {
+    let keyword = match tokens.get(*pos) {
+        Some(Token::Word(w)) => w.clone(),
+        other => return Err(format!("expected geometry keyword, found {:?}", other)),
+    };
+    *pos += 1;
+
+    match keyword.as_str() {
+        "POINT" => {
+            expect(tokens, pos, &Token::LParen)?;
+            let (x, y) = parse_coord(tokens, pos)?;
+            expect(tokens, pos, &Token::RParen)?;
+            Ok(WktGeometry::Point(x, y))
+        }
+        "LINESTRING" => {
+            expect(tokens, pos, &Token::LParen)?;
+            let coords = parse_coord_list(tokens, pos)?;
+            expect(tokens, pos, &Token::RParen)?;
+            Ok(WktGeometry::LineString(coords))
+        }
+        "POLYGON" => {
+            expect(tokens, pos, &Token::LParen)?;
+            expect(tokens, pos, &Token::LParen)?;
+            let coords = parse_coord_list(tokens, pos)?;
+            expect(tokens, pos, &Token::RParen)?;
+            expect(tokens, pos, &Token::RParen)?;
+            Ok(WktGeometry::Polygon(coords))
+        }
+        "GEOMETRYCOLLECTION" => {
+            expect(tokens, pos, &Token::LParen)?;
+            let mut geometries = Vec::new();
+            loop {
+                geometries.push(parse_geometry(tokens, pos)?);
+                match tokens.get(*pos) {
+                    Some(Token::Comma) => {
+                        *pos += 1;
+                    }
+                    _ => break,
+                }
+            }
+            expect(tokens, pos, &Token::RParen)?;
+            Ok(WktGeometry::GeometryCollection(geometries))
+        }
+        other => Err(format!("unsupported WKT geometry type: {}", other)),
+    }
+}
+
+fn parse_coord(tokens: &[Token], pos: &mut usize) -> Result<(f64, f64), String> {
+    let x = match tokens.get(*pos) {
+        Some(Token::Number(n)) => *n,
+        other => return Err(format!("expected x coordinate, found {:?}", other)),
+    };
+    *pos += 1;
+    let y = match tokens.get(*pos) {
+        Some(Token::Number(n)) => *n,
+        other => return Err(format!("expected y coordinate, found {:?}", other)),
+    };
+    *pos += 1;
+    Ok((x, y))
+}
+
+fn parse_coord_list(tokens: &[Token], pos: &mut usize) -> Result<Vec<(f64, f64)>, String> {
+    let mut coords = Vec::new();
+    loop {
+        coords.push(parse_coord(tokens, pos)?);
+        match tokens.get(*pos) {
+            Some(Token::Comma) => {
+                *pos += 1;
+            }
+            _ => break,
+        }
+    }
+    Ok(coords)
+}
+
+fn expect(tokens: &[Token], pos: &mut usize, expected: &Token) -> Result<(), String> {
+    match tokens.get(*pos) {
+        Some(t) if t == expected => {
+            *pos += 1;
+            Ok(())
+        }
+        other => Err(format!("expected {:?}, found {:?}", expected, other)),
+    }
+}
+
+/// Export a construction space as a single WKT `GEOMETRYCOLLECTION`
+pub fn export_construction(space: &ConstructionSpace) -> String {
+    let mut geometries = Vec::new();
+
+    for point in space.points() {
+        geometries.push(WktGeometry::Point(point.position.x, point.position.y));
+    }
+
+    for line in space.lines() {
+        if let (Some(p1), Some(p2)) = (
+            space.get_point(&line.point1_id),
+            space.get_point(&line.point2_id),
+        ) {
+            geometries.push(WktGeometry::LineString(vec![
+                (p1.position.x, p1.position.y),
+                (p2.position.x, p2.position.y),
+            ]));
+        }
+    }
+
+    for circle in space.circles() {
+        if let (Some(center), Some(radius_point)) = (
+            space.get_point(&circle.center_id),
+            space.get_point(&circle.radius_point_id),
+        ) {
+            let radius = center.distance_to(radius_point);
+            geometries.push(WktGeometry::Polygon(densify_circle(
+                center.position.x,
+                center.position.y,
+                radius,
+                64,
+            )));
+        }
+    }
+
+    WktGeometry::GeometryCollection(geometries).to_wkt()
+}
+
+/// Approximate a circle as a closed polygon ring with `segments` vertices
+fn densify_circle(cx: f64, cy: f64, radius: f64, segments: usize) -> Vec<(f64, f64)> {
+    let mut points = Vec::with_capacity(segments + 1);
+    for i in 0..=segments {
+        let angle = 2.0 * std::f64::consts::PI * (i as f64) / (segments as f64);
+        points.push((cx + radius * angle.cos(), cy + radius * angle.sin()));
+    }
+    points
+}
+
+/// Import a WKT string, adding the contained geometries to the construction
+/// space and returning the IDs of every newly created element.
+pub fn import_construction(space: &mut ConstructionSpace, wkt: &str) -> Result<Vec<String>, String> {
+    let geometry = WktGeometry::parse(wkt)?;
+    let mut ids = Vec::new();
+    import_geometry(space, &geometry, &mut ids)?;
+    Ok(ids)
+}
+
+fn import_geometry(
+    space: &mut ConstructionSpace,
+    geometry: &WktGeometry,
+    ids: &mut Vec<String>,
+) -> Result<(), String> {
+    match geometry {
+        WktGeometry::Point(x, y) => {
+            let id = space.add_point(Point::new(*x, *y, None));
+            ids.push(id);
+        }
+        WktGeometry::LineString(coords) => import_polyline(space, coords, ids)?,
+        WktGeometry::Polygon(coords) => import_polyline(space, coords, ids)?,
+        WktGeometry::GeometryCollection(geometries) => {
+            for g in geometries {
+                import_geometry(space, g, ids)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add each coordinate as a point and connect consecutive points with lines
+fn import_polyline(
+    space: &mut ConstructionSpace,
+    coords: &[(f64, f64)],
+    ids: &mut Vec<String>,
+) -> Result<(), String> {
+    if coords.is_empty() {
+        return Ok(());
+    }
+
+    let mut point_ids = Vec::with_capacity(coords.len());
+    for (x, y) in coords {
+        let id = space.add_point(Point::new(*x, *y, None));
+        ids.push(id.clone());
+        point_ids.push(id);
+    }
+
+    for pair in point_ids.windows(2) {
+        let line_id = space
+            .construct_line(&pair[0], &pair[1], None)
+            .map_err(|e| e.to_string())?;
+        ids.push(line_id);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_point() {
+        let geometry = WktGeometry::parse("POINT (1 2)").unwrap();
+        assert_eq!(geometry, WktGeometry::Point(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_parse_linestring() {
+        let geometry = WktGeometry::parse("LINESTRING (0 0, 1 1, 2 2)").unwrap();
+        assert_eq!(
+            geometry,
+            WktGeometry::LineString(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)])
+        );
+    }
+
+    #[test]
+    fn test_parse_polygon() {
+        let geometry = WktGeometry::parse("POLYGON ((0 0, 2 0, 2 2, 0 2))").unwrap();
+        assert_eq!(
+            geometry,
+            WktGeometry::Polygon(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)])
+        );
+    }
+
+    #[test]
+    fn test_parse_geometry_collection() {
+        let geometry =
+            WktGeometry::parse("GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (1 1, 2 2))").unwrap();
+        match geometry {
+            WktGeometry::GeometryCollection(geoms) => assert_eq!(geoms.len(), 2),
+            _ => panic!("expected a geometry collection"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_point() {
+        let wkt = "POINT (1.5 -2.5)";
+        let geometry = WktGeometry::parse(wkt).unwrap();
+        assert_eq!(geometry.to_wkt(), wkt);
+    }
+
+    #[test]
+    fn test_import_construction_point() {
+        let mut space = ConstructionSpace::new();
+        let ids = import_construction(&mut space, "POINT (3 4)").unwrap();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(space.point_count(), 1);
+    }
+
+    #[test]
+    fn test_import_construction_linestring() {
+        let mut space = ConstructionSpace::new();
+        let ids = import_construction(&mut space, "LINESTRING (0 0, 1 1)").unwrap();
+        assert_eq!(ids.len(), 3); // 2 points + 1 line
+        assert_eq!(space.point_count(), 2);
+        assert_eq!(space.line_count(), 1);
+    }
+
+    #[test]
+    fn test_export_construction_roundtrip() {
+        let mut space = ConstructionSpace::new();
+        space.add_point(Point::new(0.0, 0.0, None));
+        let wkt = export_construction(&space);
+        assert!(wkt.starts_with("GEOMETRYCOLLECTION"));
+        assert!(wkt.contains("POINT (0 0)"));
+    }
+}